@@ -1,3 +1,4 @@
+import { createHash } from "node:crypto";
 import { readFileSync } from "node:fs";
 import {
   Address,
@@ -5,13 +6,20 @@ import {
   SmartContractTransactionsFactory,
   TransactionComputer,
   ApiNetworkProvider,
+  OptionValue,
+  type Transaction,
 } from "@multiversx/sdk-core";
 import { UserSigner } from "@multiversx/sdk-wallet";
 import type { RelayStoreArgumentInput } from "@dialectical/shared";
 import { getBlockchainConfig } from "./config.js";
 import { withTransactionQueue } from "./queue.js";
 import { getSession } from "../db/neo4j.js";
-import { getOrAssignOnChainId, setArgumentTxHash } from "../db/queries/blockchain.js";
+import {
+  getOrAssignOnChainId,
+  getUnregisteredDebateTitle,
+  setArgumentTxHash,
+  setDebateTxHash,
+} from "../db/queries/blockchain.js";
 
 /**
  * RelayerService — builds and broadcasts Relayed v3 meta-transactions
@@ -78,6 +86,9 @@ export class RelayerService {
 
   /**
    * Build and broadcast a Relayed v3 transaction to store an argument on-chain.
+   * The contract only accepts arguments for registered debates, so a debate's first
+   * argument is preceded by a `createDebate` call (with the sha256 of the title),
+   * which must complete successfully before the argument is sent.
    *
    * @param input - The argument data to store.
    * @returns The transaction hash.
@@ -129,6 +140,57 @@ export class RelayerService {
       // Encode quality score: 0.0-1.0 → 0-10000
       const qualityScore = Math.round(input.qualityScore * 10000);
 
+      // Get relayer nonce
+      const relayerOnNetwork = await provider.getAccount(relayerAddress);
+      let nonce = BigInt(relayerOnNetwork.nonce);
+
+      // Set relayer for Relayed v3, sign and broadcast
+      const signAndSend = async (innerTx: Transaction): Promise<string> => {
+        innerTx.relayer = relayerAddress;
+        innerTx.nonce = nonce++;
+        const serialized = transactionComputer.computeBytesForSigning(innerTx);
+        innerTx.signature = await signer.sign(serialized);
+        return provider.sendTransaction(innerTx);
+      };
+
+      // Register the debate on its first on-chain argument
+      const debateTitleSession = getSession();
+      let debateTitle: string | null;
+      try {
+        debateTitle = await getUnregisteredDebateTitle(debateTitleSession, input.debateId);
+      } finally {
+        await debateTitleSession.close();
+      }
+
+      if (debateTitle !== null) {
+        const createDebateTx = await factory.createTransactionForExecute(relayerAddress, {
+          contract: contractAddress,
+          function: "createDebate",
+          gasLimit: BigInt(config.maxGasLimit),
+          arguments: [
+            BigInt(onChainDebateId),
+            createHash("sha256").update(debateTitle, "utf-8").digest(),
+          ],
+        });
+        const debateTxHash = await signAndSend(createDebateTx);
+
+        // Only mark the debate registered once createDebate has actually executed,
+        // otherwise a failed registration would be skipped on every later argument
+        const debateTxOnNetwork = await provider.awaitTransactionCompleted(debateTxHash);
+        if (!debateTxOnNetwork.status.isSuccessful()) {
+          throw new Error(
+            `createDebate for debate ${input.debateId} failed with status ${debateTxOnNetwork.status.toString()}`,
+          );
+        }
+
+        const debateHashSession = getSession();
+        try {
+          await setDebateTxHash(debateHashSession, input.debateId, debateTxHash);
+        } finally {
+          await debateHashSession.close();
+        }
+      }
+
       // Build the inner transaction (the SC call)
       const innerTx = await factory.createTransactionForExecute(relayerAddress, {
         contract: contractAddress,
@@ -140,23 +202,12 @@ export class RelayerService {
           argumentType,
           qualityScore,
           Buffer.from(input.text, "utf-8"),
+          // No SimHash fingerprint: the pipeline's semantic dedup stage filters near-duplicates
+          OptionValue.newMissing(),
         ],
       });
 
-      // Set relayer for Relayed v3
-      innerTx.relayer = relayerAddress;
-
-      // Get relayer nonce
-      const relayerOnNetwork = await provider.getAccount(relayerAddress);
-      innerTx.nonce = BigInt(relayerOnNetwork.nonce);
-
-      // Sign the transaction
-      const serialized = transactionComputer.computeBytesForSigning(innerTx);
-      const signature = await signer.sign(serialized);
-      innerTx.signature = signature;
-
-      // Broadcast
-      const txHash = await provider.sendTransaction(innerTx);
+      const txHash = await signAndSend(innerTx);
 
       // Store the tx hash on the Argument node in Neo4j
       const hashSession = getSession();
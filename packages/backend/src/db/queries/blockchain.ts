@@ -43,6 +43,49 @@ export async function getOrAssignOnChainId(session: Session, uuid: string): Prom
   return extractScalar<number>(record, "onChainId");
 }
 
+/**
+ * Get the title of a debate that has not been registered on-chain yet.
+ *
+ * @param session - Neo4j session.
+ * @param debateId - The app-level debate UUID.
+ * @returns The debate title, or null if the debate is already registered (or unknown).
+ */
+export async function getUnregisteredDebateTitle(
+  session: Session,
+  debateId: string,
+): Promise<string | null> {
+  const result = await session.run(
+    `MATCH (d:Debate {id: $debateId})
+     WHERE d.onChainTxHash IS NULL
+     RETURN d.title AS title`,
+    { debateId },
+  );
+
+  const record = result.records[0];
+  if (!record) return null;
+
+  return extractScalar<string>(record, "title");
+}
+
+/**
+ * Store the `createDebate` transaction hash on a Debate node, marking it registered on-chain.
+ *
+ * @param session - Neo4j session.
+ * @param debateId - The app-level debate UUID.
+ * @param txHash - The MultiversX transaction hash.
+ */
+export async function setDebateTxHash(
+  session: Session,
+  debateId: string,
+  txHash: string,
+): Promise<void> {
+  await session.run(
+    `MATCH (d:Debate {id: $debateId})
+     SET d.onChainTxHash = $txHash, d.updatedAt = $now`,
+    { debateId, txHash, now: new Date().toISOString() },
+  );
+}
+
 /**
  * Store the blockchain transaction hash on an Argument node.
  *
@@ -1,4 +1,4 @@
-use multiversx_sc::derive_imports::*;
+use multiversx_sc::{derive_imports::*, imports::*};
 
 /// On-chain argument metadata. Stored separately from the full text
 /// so that the text can live in its own SingleValueMapper<ManagedBuffer>.
@@ -13,6 +13,144 @@ pub struct ArgumentMetadata<M: multiversx_sc::api::ManagedTypeApi> {
     pub quality_score: u32,
     /// Author wallet address (zero address if no wallet linked).
     pub author: ManagedAddress<M>,
-    /// Block timestamp when stored.
+    /// Relayer that stored the argument.
+    pub relayer: ManagedAddress<M>,
+    /// Block timestamp when the latest version was stored.
+    pub timestamp: u64,
+    /// True if the author signed the argument, so the relayer cannot have forged authorship.
+    pub author_verified: bool,
+    /// Parent argument and relation to it (None for tree roots such as the thesis).
+    pub parent: Option<ArgumentLink>,
+    /// Levels below the tree root (0 for roots).
+    pub depth: u32,
+    /// Earlier argument with byte-identical content, if any.
+    pub duplicate_of: Option<u64>,
+    /// Earlier argument in the same debate whose SimHash is within the threshold, if any.
+    pub near_duplicate_of: Option<u64>,
+}
+
+/// Where an argument's content lives, as reported by the combined views.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentStatus {
+    /// Full text stored on-chain.
+    Stored,
+    /// Only a hash and locator stored on-chain (`anchorArgument`).
+    Anchored,
+    /// Content erased by a moderator; see `getRedaction`.
+    Redacted,
+}
+
+/// Argument metadata with its ID and text size, as returned by paginated views.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ArgumentRecord<M: multiversx_sc::api::ManagedTypeApi> {
+    pub id: u64,
+    pub metadata: ArgumentMetadata<M>,
+    pub status: ArgumentStatus,
+    /// On-chain text length in bytes (0 unless `status` is `Stored`).
+    pub text_length: u32,
+}
+
+/// Argument metadata together with its text, as returned by `getArgumentFull`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ArgumentFull<M: multiversx_sc::api::ManagedTypeApi> {
+    pub id: u64,
+    pub metadata: ArgumentMetadata<M>,
+    pub status: ArgumentStatus,
+    /// Latest text (empty unless `status` is `Stored`).
+    pub text: ManagedBuffer<M>,
+}
+
+/// What to do when a new argument's content hash matches an existing argument.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Store it and set `duplicate_of` to the original.
+    Mark,
+    /// Refuse to store it.
+    Reject,
+}
+
+/// 64-bit SimHash fingerprint of an argument, supplied by the relayer.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ArgumentFingerprint {
+    pub id: u64,
+    pub simhash: u64,
+}
+
+/// Edge from an argument to its parent in the debate tree.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct ArgumentLink {
+    pub parent_id: u64,
+    /// 0 = supports, 1 = attacks, 2 = refines
+    pub relation: u8,
+}
+
+/// Off-chain storage reference for an argument stored via `anchorArgument`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ArgumentAnchor<M: multiversx_sc::api::ManagedTypeApi> {
+    /// sha256 of the full argument text.
+    pub content_hash: ManagedByteArray<M, 32>,
+    /// Where the text can be fetched: IPFS CID, URL or database key.
+    pub locator: ManagedBuffer<M>,
+}
+
+/// A chunked upload started by `beginArgument` and not yet finalized.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct PendingArgument<M: multiversx_sc::api::ManagedTypeApi> {
+    pub debate_id: u64,
+    pub argument_type: u8,
+    pub quality_score: u32,
+    pub author: ManagedAddress<M>,
+    /// Relayer that began the upload; only it may append or finalize.
+    pub relayer: ManagedAddress<M>,
+    /// Declared length of the complete text in bytes.
+    pub total_length: u32,
+    /// Declared sha256 of the complete text.
+    pub content_hash: ManagedByteArray<M, 32>,
+    /// SimHash fingerprint checked and recorded on finalize, if supplied.
+    pub simhash: Option<u64>,
+}
+
+/// Payload bound to an app-side external ID by `storeArgumentAuto`, so that a
+/// retry is only accepted for the same debate and content.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ExternalArgument<M: multiversx_sc::api::ManagedTypeApi> {
+    /// Contract-assigned argument ID.
+    pub id: u64,
+    pub debate_id: u64,
+    /// sha256 of the text first stored under the external ID.
+    pub content_hash: ManagedByteArray<M, 32>,
+}
+
+/// One version of a revised argument.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ArgumentVersion<M: multiversx_sc::api::ManagedTypeApi> {
+    pub text: ManagedBuffer<M>,
+    pub quality_score: u32,
+    /// Block timestamp when this version was stored.
+    pub timestamp: u64,
+}
+
+/// Tombstone left by `redactArgument` in place of the argument's content.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ArgumentRedaction<M: multiversx_sc::api::ManagedTypeApi> {
+    /// sha256 of the content at the time of redaction.
+    pub original_hash: ManagedByteArray<M, 32>,
+    /// sha256 of each earlier version, oldest first (empty if never revised).
+    pub version_hashes: ManagedVec<M, ManagedByteArray<M, 32>>,
+    /// App-defined reason (e.g. abuse, legal request).
+    pub reason_code: u8,
+    pub moderator: ManagedAddress<M>,
+    /// Block timestamp of the redaction.
     pub timestamp: u64,
 }
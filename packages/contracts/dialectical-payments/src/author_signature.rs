@@ -0,0 +1,73 @@
+use multiversx_sc::imports::*;
+
+use crate::argument::ArgumentLink;
+
+/// `relation` byte signed for arguments that are not replies.
+pub const NO_PARENT_RELATION: u8 = 0xff;
+
+/// Author signatures — lets an author prove they wrote an argument the relayer submits.
+///
+/// The author signs, with their wallet's ed25519 key, the concatenation of:
+/// contract address (32 bytes) | id (u64 BE) | debate_id (u64 BE) |
+/// argument_type (1 byte) | parent_id (u64 BE) | relation (1 byte) |
+/// sha256(text) (32 bytes) | nonce (u64 BE).
+/// Arguments that are not replies sign parent_id 0 and relation 0xff.
+/// Nonces are per author and must be used in order, starting at 0.
+#[multiversx_sc::module]
+pub trait AuthorSignatureModule {
+    /// Next nonce an author must sign with.
+    #[view(getAuthorNonce)]
+    fn get_author_nonce(&self, author: ManagedAddress) -> u64 {
+        self.author_nonce(&author).get()
+    }
+
+    /// Verify an author's signature over an argument and consume their nonce.
+    /// Fails the transaction if the nonce is out of order or the signature is invalid.
+    fn verify_author_signature(
+        &self,
+        author: &ManagedAddress,
+        id: u64,
+        debate_id: u64,
+        argument_type: u8,
+        parent: &Option<ArgumentLink>,
+        text: &ManagedBuffer,
+        nonce: u64,
+        signature: &ManagedBuffer,
+    ) {
+        require!(
+            nonce == self.author_nonce(author).get(),
+            "Invalid author nonce"
+        );
+
+        let text_hash = self.crypto().sha256(text);
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append_bytes(&id.to_be_bytes());
+        message.append_bytes(&debate_id.to_be_bytes());
+        message.append_bytes(&[argument_type]);
+        let (parent_id, relation) = match parent {
+            Some(link) => (link.parent_id, link.relation),
+            None => (0, NO_PARENT_RELATION),
+        };
+        message.append_bytes(&parent_id.to_be_bytes());
+        message.append_bytes(&[relation]);
+        message.append(text_hash.as_managed_buffer());
+        message.append_bytes(&nonce.to_be_bytes());
+
+        self.crypto()
+            .verify_ed25519(author.as_managed_buffer(), &message, signature);
+
+        self.author_nonce(author).set(nonce + 1);
+    }
+
+    // ========================================================================
+    // Storage mappers
+    // ========================================================================
+
+    /// Next expected signature nonce per author.
+    #[storage_mapper("authorNonce")]
+    fn author_nonce(&self, author: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,41 @@
+use multiversx_sc::{derive_imports::*, imports::*};
+
+/// Lifecycle state of a debate. Only open debates accept new arguments.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum DebateState {
+    Open,
+    Closed,
+    Archived,
+}
+
+/// On-chain debate record, created by `createDebate`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct Debate<M: multiversx_sc::api::ManagedTypeApi> {
+    /// Wallet of the user who started the debate (zero address if no wallet linked).
+    pub creator: ManagedAddress<M>,
+    /// ID of the THESIS argument, once stored.
+    pub thesis_id: Option<u64>,
+    /// sha256 of the debate title.
+    pub title_hash: ManagedByteArray<M, 32>,
+    /// Block timestamp when created.
+    pub created_at: u64,
+    pub state: DebateState,
+    /// Final result, set when the debate is closed.
+    pub verdict: Option<DebateVerdict<M>>,
+}
+
+/// Outcome of a settled debate, recorded by `closeDebate`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct DebateVerdict<M: multiversx_sc::api::ManagedTypeApi> {
+    /// 0 = PRO, 1 = CON, 2 = undecided
+    pub winning_side: u8,
+    /// Arguments that decided the outcome. Must belong to the debate.
+    pub decisive_argument_ids: ManagedVec<M, u64>,
+    pub pro_votes: u64,
+    pub con_votes: u64,
+    /// Final consensus score as integer 0-10000.
+    pub final_score: u32,
+}
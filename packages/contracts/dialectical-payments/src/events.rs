@@ -1,8 +1,32 @@
 use multiversx_sc::imports::*;
 
+use crate::argument::DuplicatePolicy;
+
 /// Event definitions for the DialecticalPayments contract.
 #[multiversx_sc::module]
 pub trait EventsModule {
+    /// Emitted when a debate is registered.
+    #[event("debate_created")]
+    fn debate_created_event(
+        &self,
+        #[indexed] debate_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        created_at: u64,
+    );
+
+    /// Emitted when a debate is closed with a verdict.
+    #[event("debate_closed")]
+    fn debate_closed_event(
+        &self,
+        #[indexed] debate_id: u64,
+        #[indexed] winning_side: u8,
+        final_score: u32,
+    );
+
+    /// Emitted when a closed debate is archived.
+    #[event("debate_archived")]
+    fn debate_archived_event(&self, #[indexed] debate_id: u64, archived_at: u64);
+
     /// Emitted when an argument is stored on-chain.
     #[event("argument_stored")]
     fn argument_stored_event(
@@ -13,6 +37,24 @@ pub trait EventsModule {
         quality_score: u32,
     );
 
+    /// Emitted when an argument is revised. `version` is the new version number.
+    #[event("argument_revised")]
+    fn argument_revised_event(
+        &self,
+        #[indexed] argument_id: u64,
+        #[indexed] version: usize,
+        quality_score: u32,
+    );
+
+    /// Emitted when a moderator redacts an argument.
+    #[event("argument_redacted")]
+    fn argument_redacted_event(
+        &self,
+        #[indexed] argument_id: u64,
+        #[indexed] moderator: &ManagedAddress,
+        reason_code: u8,
+    );
+
     /// Emitted when a user subscribes.
     #[event("subscription_created")]
     fn subscription_created_event(
@@ -22,7 +64,193 @@ pub trait EventsModule {
         expires_at: u64,
     );
 
+    /// Emitted when a user moves an active subscription to another tier.
+    #[event("subscription_tier_changed")]
+    fn subscription_tier_changed_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] old_tier: u8,
+        #[indexed] new_tier: u8,
+        #[indexed] amount_paid: &BigUint,
+        expires_at: u64,
+    );
+
     /// Emitted when a user cancels their subscription.
     #[event("subscription_cancelled")]
     fn subscription_cancelled_event(&self, #[indexed] user: &ManagedAddress);
+
+    /// Emitted when the owner withdraws EGLD from the treasury.
+    #[event("treasury_withdrawn")]
+    fn treasury_withdrawn_event(
+        &self,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+        timestamp: u64,
+    );
+
+    /// Emitted when the owner replaces the revenue split.
+    #[event("revenue_split_updated")]
+    fn revenue_split_updated_event(&self, total_share_bps: u32);
+
+    /// Emitted when a payee claims their revenue share.
+    #[event("revenue_claimed")]
+    fn revenue_claimed_event(&self, #[indexed] payee: &ManagedAddress, amount: &BigUint);
+
+    /// Emitted when a role is granted. `role` is a single role bit.
+    #[event("role_granted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] role: u8,
+        granted_by: &ManagedAddress,
+    );
+
+    /// Emitted when a role is revoked. `role` is a single role bit.
+    #[event("role_revoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] role: u8,
+        revoked_by: &ManagedAddress,
+    );
+
+    /// Emitted when endpoint groups are paused, with the paused-groups mask before and after.
+    #[event("paused")]
+    fn paused_event(&self, #[indexed] old_mask: u8, #[indexed] new_mask: u8, by: &ManagedAddress);
+
+    /// Emitted when endpoint groups are resumed, with the paused-groups mask before and after.
+    #[event("unpaused")]
+    fn unpaused_event(&self, #[indexed] old_mask: u8, #[indexed] new_mask: u8, by: &ManagedAddress);
+
+    /// Emitted when an admin action is queued behind the timelock.
+    #[event("action_queued")]
+    fn action_queued_event(
+        &self,
+        #[indexed] action_id: u64,
+        #[indexed] eta: u64,
+        proposer: &ManagedAddress,
+    );
+
+    /// Emitted when a queued admin action is cancelled.
+    #[event("action_cancelled")]
+    fn action_cancelled_event(&self, #[indexed] action_id: u64, by: &ManagedAddress);
+
+    /// Emitted when a queued admin action is executed.
+    #[event("action_executed")]
+    fn action_executed_event(&self, #[indexed] action_id: u64, by: &ManagedAddress);
+
+    /// Emitted when the owner proposes a successor.
+    #[event("ownership_proposed")]
+    fn ownership_proposed_event(
+        &self,
+        #[indexed] current_owner: &ManagedAddress,
+        #[indexed] proposed_owner: &ManagedAddress,
+    );
+
+    /// Emitted when the proposed owner accepts ownership.
+    #[event("ownership_transferred")]
+    fn ownership_transferred_event(
+        &self,
+        #[indexed] previous_owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );
+
+    /// Emitted when a tier's price changes.
+    #[event("tier_price_changed")]
+    fn tier_price_changed_event(
+        &self,
+        #[indexed] tier: u8,
+        #[indexed] old_price: &BigUint,
+        #[indexed] new_price: &BigUint,
+        timestamp: u64,
+    );
+
+    /// Emitted when a tier's monthly argument quota changes.
+    #[event("tier_quota_changed")]
+    fn tier_quota_changed_event(
+        &self,
+        #[indexed] tier: u8,
+        #[indexed] old_quota: u64,
+        new_quota: u64,
+    );
+
+    /// Emitted when a relayer is whitelisted or its settings change. A new relayer
+    /// has an empty `old_label` and an `old_daily_cap` of 0.
+    #[event("relayer_updated")]
+    fn relayer_updated_event(
+        &self,
+        #[indexed] relayer: &ManagedAddress,
+        #[indexed] old_label: &ManagedBuffer,
+        #[indexed] new_label: &ManagedBuffer,
+        #[indexed] old_daily_cap: u64,
+        new_daily_cap: u64,
+    );
+
+    /// Emitted when a relayer is removed from the whitelist.
+    #[event("relayer_removed")]
+    fn relayer_removed_event(
+        &self,
+        #[indexed] relayer: &ManagedAddress,
+        #[indexed] label: &ManagedBuffer,
+        daily_cap: u64,
+    );
+
+    /// Emitted when the global duplicate content policy changes.
+    #[event("duplicate_policy_changed")]
+    fn duplicate_policy_changed_event(
+        &self,
+        #[indexed] old_policy: DuplicatePolicy,
+        new_policy: DuplicatePolicy,
+    );
+
+    /// Emitted when a debate's duplicate policy override is set or cleared (None).
+    #[event("debate_duplicate_policy_changed")]
+    fn debate_duplicate_policy_changed_event(
+        &self,
+        #[indexed] debate_id: u64,
+        #[indexed] old_policy: Option<DuplicatePolicy>,
+        new_policy: Option<DuplicatePolicy>,
+    );
+
+    /// Emitted when the SimHash settings change.
+    #[event("simhash_settings_changed")]
+    fn simhash_settings_changed_event(
+        &self,
+        #[indexed] old_threshold: u32,
+        #[indexed] new_threshold: u32,
+        #[indexed] old_scan_limit: u32,
+        new_scan_limit: u32,
+    );
+
+    /// Emitted when the timelock delay changes.
+    #[event("timelock_delay_changed")]
+    fn timelock_delay_changed_event(&self, #[indexed] old_delay: u64, new_delay: u64);
+
+    /// Emitted when the default withdrawal recipient changes.
+    #[event("payout_address_changed")]
+    fn payout_address_changed_event(
+        &self,
+        #[indexed] old_address: &ManagedAddress,
+        new_address: &ManagedAddress,
+    );
+
+    /// Emitted when the withdrawal caps change.
+    #[event("withdrawal_limits_changed")]
+    fn withdrawal_limits_changed_event(
+        &self,
+        #[indexed] old_max_per_call: &BigUint,
+        #[indexed] new_max_per_call: &BigUint,
+        #[indexed] old_max_per_day: &BigUint,
+        new_max_per_day: &BigUint,
+    );
+
+    /// Emitted for each payee whose share changes when the revenue split is replaced.
+    /// Removed payees have a `new_share_bps` of 0.
+    #[event("revenue_share_changed")]
+    fn revenue_share_changed_event(
+        &self,
+        #[indexed] payee: &ManagedAddress,
+        #[indexed] old_share_bps: u32,
+        new_share_bps: u32,
+    );
 }
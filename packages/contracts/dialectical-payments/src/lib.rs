@@ -3,21 +3,60 @@
 use multiversx_sc::imports::*;
 
 pub mod argument;
+pub mod author_signature;
+pub mod debate;
 pub mod events;
+pub mod ownership;
+pub mod pausable;
+pub mod relayers;
+pub mod revenue_split;
+pub mod roles;
 pub mod subscription;
+pub mod timelock;
+pub mod treasury;
 
-use argument::ArgumentMetadata;
-use subscription::SubscriptionInfo;
+use argument::{
+    ArgumentAnchor, ArgumentFingerprint, ArgumentFull, ArgumentLink, ArgumentMetadata,
+    ArgumentRecord, ArgumentRedaction, ArgumentStatus, ArgumentVersion, DuplicatePolicy,
+    ExternalArgument, PendingArgument,
+};
+use debate::{Debate, DebateState, DebateVerdict};
+use pausable::{PAUSE_ARGUMENTS, PAUSE_SUBSCRIPTIONS};
+use roles::{ROLE_ADMIN, ROLE_MODERATOR, ROLE_PRICING_MANAGER};
+use subscription::{PriceChange, SECONDS_PER_30_DAYS, SubscriptionInfo};
+use timelock::AdminAction;
 
-const SECONDS_PER_30_DAYS: u64 = 30 * 24 * 60 * 60;
 const MAX_TIER: u8 = 3;
+const ARGUMENT_TYPE_THESIS: u8 = 2;
+/// Deepest reply level below a root argument (mirrors PIPELINE_THRESHOLDS.maxTreeDepth).
+const MAX_TREE_DEPTH: u32 = 10;
+/// Most arguments accepted by one `storeArguments` call, to stay within the block gas limit.
+const MAX_BATCH_SIZE: usize = 50;
+/// Most records returned by one paginated view call.
+const MAX_PAGE_SIZE: usize = 100;
+/// Most index positions read by one quality-filtered view call.
+const MAX_FILTER_SCAN: usize = 1_000;
+/// SimHash fingerprints compared per store until `setSimhashSettings` is called.
+const DEFAULT_SIMHASH_SCAN_LIMIT: u32 = 100;
+/// Most SimHash fingerprints compared per store, to bound the gas of each store.
+const MAX_SIMHASH_SCAN_LIMIT: u32 = 1_000;
 
 /// DialecticalPayments — on-chain argument storage and subscription management.
 ///
-/// Arguments are stored by a trusted relayer (meta-transactions via Relayed v3).
-/// Subscriptions are paid directly in EGLD with tier pricing set by the owner.
+/// Arguments are stored by whitelisted relayers (meta-transactions via Relayed v3).
+/// Subscriptions are paid directly in EGLD with tier pricing set by a pricing manager.
 #[multiversx_sc::contract]
-pub trait DialecticalPayments: events::EventsModule {
+pub trait DialecticalPayments:
+    events::EventsModule
+    + author_signature::AuthorSignatureModule
+    + ownership::OwnershipModule
+    + roles::RolesModule
+    + pausable::PausableModule
+    + timelock::TimelockModule
+    + relayers::RelayersModule
+    + revenue_split::RevenueSplitModule
+    + treasury::TreasuryModule
+{
     /// Deploy. No special init logic.
     #[init]
     fn init(&self) {}
@@ -30,10 +69,13 @@ pub trait DialecticalPayments: events::EventsModule {
     // ========================================================================
 
     /// Subscribe by paying the exact EGLD amount for the requested tier.
-    /// Creates or renews a 30-day subscription.
+    /// Creates a 30-day subscription, or renews one: renewing an active subscription
+    /// of the same tier adds 30 days to the current expiry. The usage counter only
+    /// resets when a new billing period begins.
     #[payable("EGLD")]
     #[endpoint(subscribe)]
     fn subscribe(&self, tier: u8) {
+        self.require_not_paused(PAUSE_SUBSCRIPTIONS);
         require!(tier >= 1 && tier <= MAX_TIER, "Invalid tier (1-3)");
 
         let price = self.tier_pricing(tier).get();
@@ -44,21 +86,71 @@ pub trait DialecticalPayments: events::EventsModule {
 
         let caller = self.blockchain().get_caller();
         let now = self.blockchain().get_block_timestamp();
-        let expires_at = now + SECONDS_PER_30_DAYS;
 
-        let info = SubscriptionInfo {
-            tier,
-            expires_at,
-            arguments_used: 0,
-        };
+        let mut info = self.check_subscription(caller.clone());
+        require!(
+            info.tier == tier || !info.is_active(now),
+            "Active subscription has a different tier, use changeTier"
+        );
+        if info.is_active(now) {
+            info.expires_at += SECONDS_PER_30_DAYS;
+            info.advance_period(now);
+        } else {
+            let expires_at = now + SECONDS_PER_30_DAYS;
+            info = SubscriptionInfo {
+                tier,
+                expires_at,
+                arguments_used: 0,
+                period_ends_at: expires_at,
+            };
+        }
 
+        let expires_at = info.expires_at;
         self.subscriptions(&caller).set(info);
+        self.record_revenue(&payment);
+        self.distribute_revenue(&payment);
         self.subscription_created_event(&caller, tier, expires_at);
     }
 
+    /// Move an active subscription to another tier, prorated over the time left.
+    /// Upgrades must pay at least the prorated price difference (excess is refunded)
+    /// and keep the same expiry. Downgrades are free and turn the remaining value
+    /// into extra time on the cheaper tier.
+    #[payable("EGLD")]
+    #[endpoint(changeTier)]
+    fn change_tier(&self, new_tier: u8) {
+        self.require_not_paused(PAUSE_SUBSCRIPTIONS);
+        let caller = self.blockchain().get_caller();
+        let (cost, expires_at) = self
+            .get_tier_change_quote(caller.clone(), new_tier)
+            .into_tuple();
+
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment >= cost, "Insufficient payment for tier upgrade");
+
+        let mut info = self.check_subscription(caller.clone());
+        let old_tier = info.tier;
+        info.tier = new_tier;
+        info.expires_at = expires_at;
+        info.period_ends_at = info.period_ends_at.min(expires_at);
+        self.subscriptions(&caller).set(info);
+
+        if cost > 0u32 {
+            self.record_revenue(&cost);
+            self.distribute_revenue(&cost);
+        }
+        let refund = payment - &cost;
+        if refund > 0u32 {
+            self.send().direct_egld(&caller, &refund);
+        }
+
+        self.subscription_tier_changed_event(&caller, old_tier, new_tier, &cost, expires_at);
+    }
+
     /// Cancel the caller's subscription. No refund. Clears the subscription record.
     #[endpoint(cancelSubscription)]
     fn cancel_subscription(&self) {
+        self.require_not_paused(PAUSE_SUBSCRIPTIONS);
         let caller = self.blockchain().get_caller();
         require!(
             !self.subscriptions(&caller).is_empty(),
@@ -69,8 +161,8 @@ pub trait DialecticalPayments: events::EventsModule {
         self.subscription_cancelled_event(&caller);
     }
 
-    /// View the subscription info for an address.
-    /// Returns default (tier=0, expires_at=0, arguments_used=0) if never subscribed.
+    /// View the subscription info for an address, with the billing period advanced
+    /// to the current block. Returns default (all zero) if never subscribed.
     #[view(checkSubscription)]
     fn check_subscription(&self, address: ManagedAddress) -> SubscriptionInfo {
         if self.subscriptions(&address).is_empty() {
@@ -78,17 +170,223 @@ pub trait DialecticalPayments: events::EventsModule {
                 tier: 0,
                 expires_at: 0,
                 arguments_used: 0,
+                period_ends_at: 0,
             };
         }
-        self.subscriptions(&address).get()
+        let mut info = self.subscriptions(&address).get();
+        info.advance_period(self.blockchain().get_block_timestamp());
+        info
+    }
+
+    /// Quote a tier change for an address at the current block:
+    /// (EGLD due, resulting expiry). Upgrades cost the price difference for the
+    /// seconds left; downgrades cost nothing and extend the expiry.
+    #[view(getTierChangeQuote)]
+    fn get_tier_change_quote(
+        &self,
+        address: ManagedAddress,
+        new_tier: u8,
+    ) -> MultiValue2<BigUint, u64> {
+        require!(new_tier >= 1 && new_tier <= MAX_TIER, "Invalid tier (1-3)");
+
+        let now = self.blockchain().get_block_timestamp();
+        let info = self.check_subscription(address);
+        require!(info.is_active(now), "No active subscription");
+        require!(info.tier != new_tier, "Already on this tier");
+
+        let old_price = self.tier_pricing(info.tier).get();
+        let new_price = self.tier_pricing(new_tier).get();
+        require!(new_price != 0u32, "Tier pricing not set");
+
+        let remaining = info.expires_at - now;
+        if new_price > old_price {
+            let cost = (new_price - old_price) * remaining / SECONDS_PER_30_DAYS;
+            return (cost, info.expires_at).into();
+        }
+
+        let remaining_value = old_price * remaining;
+        let new_remaining = (remaining_value / new_price).to_u64().unwrap_or(u64::MAX);
+        (BigUint::zero(), now.saturating_add(new_remaining)).into()
+    }
+
+    /// Count `count` arguments against a user's quota for the current billing period.
+    /// Only callable by a whitelisted relayer. Fails once the tier quota is exhausted.
+    #[endpoint(consumeQuota)]
+    fn consume_quota(&self, user: ManagedAddress, count: u64) {
+        self.require_not_paused(PAUSE_SUBSCRIPTIONS);
+        let caller = self.blockchain().get_caller();
+        require!(self.is_relayer(&caller), "Only relayer can consume quota");
+        require!(count > 0, "Count must be greater than zero");
+        self.consume_quota_for(&user, count);
+    }
+
+    /// Every price change of a tier, oldest first.
+    #[view(getTierPriceHistory)]
+    fn get_tier_price_history(&self, tier: u8) -> MultiValueEncoded<PriceChange<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let history = self.tier_price_history(tier);
+        for i in 1..=history.len() {
+            result.push(history.get(i));
+        }
+        result
+    }
+
+    /// The price of a tier in force at `timestamp`, per its price history.
+    /// Zero before the tier was first priced.
+    #[view(getTierPriceAt)]
+    fn get_tier_price_at(&self, tier: u8, timestamp: u64) -> BigUint {
+        let history = self.tier_price_history(tier);
+        if history.is_empty() {
+            return self.tier_pricing(tier).get();
+        }
+
+        // Number of changes made at or before `timestamp`
+        let (mut low, mut high) = (0, history.len());
+        while low < high {
+            let mid = (low + high) / 2;
+            if history.get(mid + 1).timestamp <= timestamp {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 0 {
+            return history.get(1).old_price;
+        }
+        history.get(low).new_price
+    }
+
+    /// Monthly argument quota for a tier (0 = unlimited).
+    #[view(getTierQuota)]
+    fn get_tier_quota(&self, tier: u8) -> u64 {
+        self.tier_quota(tier).get()
+    }
+
+    /// Advance the user's billing period and add `count` to `arguments_used`.
+    fn consume_quota_for(&self, user: &ManagedAddress, count: u64) {
+        let now = self.blockchain().get_block_timestamp();
+        let mut info = self.check_subscription(user.clone());
+        require!(info.is_active(now), "No active subscription");
+
+        let arguments_used = info
+            .arguments_used
+            .checked_add(count)
+            .unwrap_or_else(|| sc_panic!("Argument quota exhausted"));
+        let quota = self.tier_quota(info.tier).get();
+        require!(
+            quota == 0 || arguments_used <= quota,
+            "Argument quota exhausted"
+        );
+
+        info.arguments_used = arguments_used;
+        self.subscriptions(user).set(info);
+    }
+
+    // ========================================================================
+    // Debate endpoints
+    // ========================================================================
+
+    /// Register a debate under its app-assigned ID. Arguments can only be stored
+    /// for registered, open debates. Only callable by a whitelisted relayer.
+    #[endpoint(createDebate)]
+    fn create_debate(
+        &self,
+        debate_id: u64,
+        title_hash: ManagedByteArray<32>,
+        creator: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_relayer();
+        self.require_not_paused(PAUSE_ARGUMENTS);
+        require!(self.debates(debate_id).is_empty(), "Debate already exists");
+
+        let creator = creator.into_option().unwrap_or_else(ManagedAddress::zero);
+        let created_at = self.blockchain().get_block_timestamp();
+
+        self.debate_created_event(debate_id, &creator, created_at);
+        self.debates(debate_id).set(Debate {
+            creator,
+            thesis_id: None,
+            title_hash,
+            created_at,
+            state: DebateState::Open,
+            verdict: None,
+        });
+    }
+
+    /// Close an open debate with its final verdict. The debate is frozen:
+    /// no further arguments can be stored for it. Only callable by a relayer or an admin.
+    #[endpoint(closeDebate)]
+    fn close_debate(&self, debate_id: u64, verdict: DebateVerdict<Self::Api>) {
+        self.require_not_paused(PAUSE_ARGUMENTS);
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_relayer(&caller) || self.holds_role(&caller, ROLE_ADMIN),
+            "Only relayer or admin can close debates"
+        );
+        require!(!self.debates(debate_id).is_empty(), "Debate not found");
+        require!(verdict.winning_side <= 2, "Invalid winning side (0-2)");
+        require!(verdict.final_score <= 10_000, "Final score must be 0-10000");
+        for id in verdict.decisive_argument_ids.iter() {
+            require!(
+                !self.argument_metadata(id).is_empty()
+                    && self.argument_metadata(id).get().debate_id == debate_id,
+                "Decisive argument not in debate"
+            );
+        }
+
+        let mut debate = self.debates(debate_id).get();
+        require!(debate.state == DebateState::Open, "Debate is not open");
+
+        let winning_side = verdict.winning_side;
+        let final_score = verdict.final_score;
+        debate.state = DebateState::Closed;
+        debate.verdict = Some(verdict);
+        self.debates(debate_id).set(debate);
+
+        self.debate_closed_event(debate_id, winning_side, final_score);
+    }
+
+    /// Archive a closed debate, the final lifecycle state. The verdict is kept.
+    /// Only callable by a relayer or an admin.
+    #[endpoint(archiveDebate)]
+    fn archive_debate(&self, debate_id: u64) {
+        self.require_not_paused(PAUSE_ARGUMENTS);
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_relayer(&caller) || self.holds_role(&caller, ROLE_ADMIN),
+            "Only relayer or admin can archive debates"
+        );
+        require!(!self.debates(debate_id).is_empty(), "Debate not found");
+        self.debates(debate_id).update(|debate| {
+            require!(debate.state == DebateState::Closed, "Debate is not closed");
+            debate.state = DebateState::Archived;
+        });
+
+        self.debate_archived_event(debate_id, self.blockchain().get_block_timestamp());
+    }
+
+    /// Get the full debate record.
+    #[view(getDebate)]
+    fn get_debate(&self, debate_id: u64) -> Debate<Self::Api> {
+        require!(!self.debates(debate_id).is_empty(), "Debate not found");
+        self.debates(debate_id).get()
     }
 
     // ========================================================================
     // Argument storage endpoints
     // ========================================================================
 
-    /// Store an argument on-chain. Only callable by the designated relayer.
+    /// Store an argument on-chain. Only callable by a whitelisted relayer.
     /// Does NOT accept payment — relayer pays gas via Relayed v3.
+    /// If a non-zero `author` is given, the author must have an active subscription;
+    /// the argument counts against their quota and is indexed under their address.
+    /// Passing the author's `(nonce, signature)` marks the argument as author-verified.
+    /// An optional 64-bit SimHash `simhash` is compared by Hamming distance against
+    /// earlier fingerprints in the debate (see `setSimhashSettings`); a match closer
+    /// than the threshold is rejected or recorded in `near_duplicate_of`, per the
+    /// debate's duplicate policy.
+    #[allow_multiple_var_args]
     #[endpoint(storeArgument)]
     fn store_argument(
         &self,
@@ -97,42 +395,478 @@ pub trait DialecticalPayments: events::EventsModule {
         argument_type: u8,
         quality_score: u32,
         full_text: ManagedBuffer,
+        simhash: Option<u64>,
+        author: OptionalValue<ManagedAddress>,
+        author_signature: OptionalValue<MultiValue2<u64, ManagedBuffer>>,
     ) {
-        let caller = self.blockchain().get_caller();
-        let relayer = self.relayer().get();
-        require!(caller == relayer, "Only relayer can store arguments");
-        require!(argument_type <= 2, "Invalid argument type (0-2)");
-        require!(quality_score <= 10_000, "Quality score must be 0-10000");
-        require!(!full_text.is_empty(), "Text cannot be empty");
-        require!(
-            self.argument_text(id).is_empty(),
-            "Argument ID already exists"
+        self.store_text_argument(
+            id,
+            debate_id,
+            argument_type,
+            quality_score,
+            full_text,
+            simhash,
+            None,
+            author,
+            author_signature,
         );
+    }
 
-        let now = self.blockchain().get_block_timestamp();
+    /// Store an argument as a child of `parent_id` in the same debate's tree.
+    /// `relation`: 0 = supports, 1 = attacks, 2 = refines. Trees are limited to
+    /// `MAX_TREE_DEPTH` levels below the root. An author signature also covers
+    /// `parent_id` and `relation`. Otherwise as `storeArgument`.
+    #[allow_multiple_var_args]
+    #[endpoint(storeReply)]
+    fn store_reply(
+        &self,
+        id: u64,
+        debate_id: u64,
+        argument_type: u8,
+        quality_score: u32,
+        full_text: ManagedBuffer,
+        simhash: Option<u64>,
+        parent_id: u64,
+        relation: u8,
+        author: OptionalValue<ManagedAddress>,
+        author_signature: OptionalValue<MultiValue2<u64, ManagedBuffer>>,
+    ) {
+        self.store_text_argument(
+            id,
+            debate_id,
+            argument_type,
+            quality_score,
+            full_text,
+            simhash,
+            Some(ArgumentLink {
+                parent_id,
+                relation,
+            }),
+            author,
+            author_signature,
+        );
+    }
 
-        let metadata = ArgumentMetadata {
+    /// Store an argument under an ID assigned by the contract and return that ID.
+    /// `external_id` is the app's own ID for the argument: calling again with an
+    /// external ID that was already stored returns the original ID without storing
+    /// anything, so retries are idempotent. A reused external ID with a different
+    /// debate or text is rejected. Otherwise as `storeArgument` (unsigned).
+    #[endpoint(storeArgumentAuto)]
+    fn store_argument_auto(
+        &self,
+        external_id: u64,
+        debate_id: u64,
+        argument_type: u8,
+        quality_score: u32,
+        full_text: ManagedBuffer,
+        simhash: Option<u64>,
+        author: OptionalValue<ManagedAddress>,
+    ) -> u64 {
+        self.require_relayer();
+        require!(external_id != 0, "External ID cannot be zero");
+
+        let content_hash = self.crypto().sha256(&full_text);
+        if !self.external_argument(external_id).is_empty() {
+            let existing = self.external_argument(external_id).get();
+            require!(
+                existing.debate_id == debate_id && existing.content_hash == content_hash,
+                "External ID already used for a different argument"
+            );
+            return existing.id;
+        }
+
+        // Skip IDs already taken through the caller-assigned endpoints
+        let mut id = self.next_argument_id().get().max(1);
+        while !self.argument_metadata(id).is_empty() || !self.pending_argument(id).is_empty() {
+            id += 1;
+        }
+        self.next_argument_id().set(id + 1);
+        self.external_argument(external_id).set(ExternalArgument {
+            id,
+            debate_id,
+            content_hash,
+        });
+
+        self.store_text_argument(
+            id,
             debate_id,
             argument_type,
             quality_score,
-            author: ManagedAddress::zero(),
-            timestamp: now,
-        };
+            full_text,
+            simhash,
+            None,
+            author,
+            OptionalValue::None,
+        );
+        id
+    }
+
+    /// Store several arguments in one transaction, e.g. the output of a pipeline run.
+    /// Each item is `(id, debate_id, type, score, text, simhash)` and goes through the same
+    /// checks as `storeArgument` (without author). The batch is all-or-nothing and
+    /// limited to `MAX_BATCH_SIZE` items. Only callable by a whitelisted relayer.
+    #[endpoint(storeArguments)]
+    fn store_arguments(
+        &self,
+        arguments: MultiValueEncoded<MultiValue6<u64, u64, u8, u32, ManagedBuffer, Option<u64>>>,
+    ) {
+        self.require_relayer();
+        let count = arguments.len();
+        require!(count > 0, "Batch cannot be empty");
+        require!(count <= MAX_BATCH_SIZE, "Batch exceeds maximum size");
+
+        for item in arguments.into_iter() {
+            let (id, debate_id, argument_type, quality_score, full_text, simhash) =
+                item.into_tuple();
+            self.store_text_argument(
+                id,
+                debate_id,
+                argument_type,
+                quality_score,
+                full_text,
+                simhash,
+                None,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        }
+    }
+
+    /// Anchor an argument whose text lives off-chain. Stores only the sha256
+    /// `content_hash` of the text and a `locator` (IPFS CID, URL or DB key).
+    /// Only callable by a whitelisted relayer. Author handling as in `storeArgument`.
+    #[endpoint(anchorArgument)]
+    fn anchor_argument(
+        &self,
+        id: u64,
+        debate_id: u64,
+        argument_type: u8,
+        quality_score: u32,
+        content_hash: ManagedByteArray<32>,
+        locator: ManagedBuffer,
+        simhash: Option<u64>,
+        author: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_relayer();
+        require!(!locator.is_empty(), "Locator cannot be empty");
+
+        let author = author.into_option().unwrap_or_else(ManagedAddress::zero);
+        self.record_argument(
+            id,
+            debate_id,
+            argument_type,
+            quality_score,
+            &content_hash,
+            simhash,
+            None,
+            author,
+            false,
+        );
+        self.argument_anchor(id).set(ArgumentAnchor {
+            content_hash,
+            locator,
+        });
+
+        self.argument_stored_event(id, debate_id, argument_type, quality_score);
+    }
+
+    /// Start a chunked upload for an argument too large for one transaction.
+    /// Declares the final text length and sha256 hash, which `finalizeArgument`
+    /// checks. Only callable by a whitelisted relayer; the upload belongs to it.
+    #[endpoint(beginArgument)]
+    fn begin_argument(
+        &self,
+        id: u64,
+        debate_id: u64,
+        argument_type: u8,
+        quality_score: u32,
+        total_length: u32,
+        content_hash: ManagedByteArray<32>,
+        simhash: Option<u64>,
+        author: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_relayer();
+        self.validate_new_argument(id, debate_id, argument_type, quality_score);
+        require!(total_length > 0, "Text cannot be empty");
 
+        self.pending_argument(id).set(PendingArgument {
+            debate_id,
+            argument_type,
+            quality_score,
+            author: author.into_option().unwrap_or_else(ManagedAddress::zero),
+            relayer: self.blockchain().get_caller(),
+            total_length,
+            content_hash,
+            simhash,
+        });
+    }
+
+    /// Append the next chunk of text to a pending upload.
+    #[endpoint(appendArgumentChunk)]
+    fn append_argument_chunk(&self, id: u64, chunk: ManagedBuffer) {
+        self.require_not_paused(PAUSE_ARGUMENTS);
+        let pending = self.require_pending_owner(id);
+        require!(!chunk.is_empty(), "Chunk cannot be empty");
+
+        self.pending_argument_text(id).update(|text| {
+            require!(
+                text.len() + chunk.len() <= pending.total_length as usize,
+                "Chunk exceeds declared length"
+            );
+            text.append(&chunk);
+        });
+    }
+
+    /// Check the uploaded text against the declared length and hash and store it
+    /// as a regular argument. Only then does it become visible and emit `argument_stored`.
+    #[endpoint(finalizeArgument)]
+    fn finalize_argument(&self, id: u64) {
+        let pending = self.require_pending_owner(id);
+        let full_text = self.pending_argument_text(id).take();
+        require!(
+            full_text.len() == pending.total_length as usize,
+            "Text length does not match declared length"
+        );
+        require!(
+            self.crypto().sha256(&full_text) == pending.content_hash,
+            "Text hash does not match declared hash"
+        );
+
+        self.pending_argument(id).clear();
+        self.record_argument(
+            id,
+            pending.debate_id,
+            pending.argument_type,
+            pending.quality_score,
+            &pending.content_hash,
+            pending.simhash,
+            None,
+            pending.author,
+            false,
+        );
         self.argument_text(id).set(full_text);
+
+        self.argument_stored_event(
+            id,
+            pending.debate_id,
+            pending.argument_type,
+            pending.quality_score,
+        );
+    }
+
+    /// Discard a pending upload and free its ID. Callable by the relayer that began
+    /// it, by an admin, or by any relayer once the original one has been removed.
+    #[endpoint(abortArgument)]
+    fn abort_argument(&self, id: u64) {
+        require!(!self.pending_argument(id).is_empty(), "No pending upload");
+        let pending = self.pending_argument(id).get();
+        let caller = self.blockchain().get_caller();
+        let may_abort = if self.is_relayer(&caller) {
+            pending.relayer == caller || !self.is_relayer(&pending.relayer)
+        } else {
+            self.holds_role(&caller, ROLE_ADMIN)
+        };
+        require!(may_abort, "Upload belongs to another relayer");
+        self.pending_argument(id).clear();
+        self.pending_argument_text(id).clear();
+    }
+
+    /// Replace an argument's text and quality score with a refined version.
+    /// Earlier versions are kept and stay readable via `getArgumentVersion`.
+    /// The new text is not author-signed, so `author_verified` is cleared, and it goes
+    /// through the same duplicate-content check as a newly stored argument.
+    /// Only callable by a whitelisted relayer, for full-text arguments in open debates.
+    #[endpoint(reviseArgument)]
+    fn revise_argument(&self, id: u64, new_text: ManagedBuffer, new_quality_score: u32) {
+        self.require_not_paused(PAUSE_ARGUMENTS);
+        self.require_relayer();
+        require!(!self.argument_metadata(id).is_empty(), "Argument not found");
+        require!(
+            self.argument_anchor(id).is_empty(),
+            "Anchored arguments cannot be revised"
+        );
+        require!(
+            self.argument_redaction(id).is_empty(),
+            "Argument has been redacted"
+        );
+        require!(!new_text.is_empty(), "Text cannot be empty");
+        require!(new_quality_score <= 10_000, "Quality score must be 0-10000");
+
+        let mut metadata = self.argument_metadata(id).get();
+        require!(
+            self.debates(metadata.debate_id).get().state == DebateState::Open,
+            "Debate is not open"
+        );
+
+        let previous = ArgumentVersion {
+            text: self.argument_text(id).get(),
+            quality_score: metadata.quality_score,
+            timestamp: metadata.timestamp,
+        };
+        let version = self.argument_versions(id).push(&previous) + 1;
+
+        metadata.duplicate_of =
+            self.check_duplicate_content(id, metadata.debate_id, &self.crypto().sha256(&new_text));
+        metadata.quality_score = new_quality_score;
+        metadata.timestamp = self.blockchain().get_block_timestamp();
+        metadata.author_verified = false;
         self.argument_metadata(id).set(metadata);
-        self.debate_arguments(debate_id).push(&id);
+        self.argument_text(id).set(new_text);
 
-        self.argument_stored_event(id, debate_id, argument_type, quality_score);
+        self.argument_revised_event(id, version, new_quality_score);
     }
 
-    /// Get the full text of a stored argument.
+    /// Get the full text of the latest version of a stored argument.
     #[view(getArgument)]
     fn get_argument(&self, id: u64) -> ManagedBuffer {
+        require!(
+            self.argument_redaction(id).is_empty(),
+            "Argument has been redacted"
+        );
+        require!(
+            self.argument_anchor(id).is_empty(),
+            "Argument text is stored off-chain"
+        );
         require!(!self.argument_text(id).is_empty(), "Argument not found");
         self.argument_text(id).get()
     }
 
+    /// Get the contract-assigned ID of an argument stored via `storeArgumentAuto`.
+    #[view(getArgumentIdByExternalId)]
+    fn get_argument_id_by_external_id(&self, external_id: u64) -> u64 {
+        require!(
+            !self.external_argument(external_id).is_empty(),
+            "External ID not found"
+        );
+        self.external_argument(external_id).get().id
+    }
+
+    /// Find the fingerprinted argument in a debate closest to `simhash`, within the
+    /// scan limit. Returns `(argument id, Hamming distance)`.
+    #[view(getNearestFingerprintMatch)]
+    fn get_nearest_fingerprint_match(&self, debate_id: u64, simhash: u64) -> MultiValue2<u64, u32> {
+        match self.nearest_fingerprint(debate_id, simhash) {
+            Some(nearest) => nearest.into(),
+            None => sc_panic!("No fingerprints in debate"),
+        }
+    }
+
+    /// Get the first argument stored with the given sha256 content hash.
+    /// Later byte-identical arguments point to it through `duplicate_of`.
+    #[view(findArgumentByHash)]
+    fn find_argument_by_hash(&self, content_hash: ManagedByteArray<32>) -> u64 {
+        let id = self.content_hash_argument(&content_hash).get();
+        require!(id != 0, "No argument with this hash");
+        id
+    }
+
+    /// Duplicate policy in effect for a debate: its own if set, else the global one.
+    #[view(getDuplicatePolicy)]
+    fn get_duplicate_policy(&self, debate_id: u64) -> DuplicatePolicy {
+        if !self.debate_duplicate_policy(debate_id).is_empty() {
+            return self.debate_duplicate_policy(debate_id).get();
+        }
+        if self.duplicate_policy().is_empty() {
+            return DuplicatePolicy::Mark;
+        }
+        self.duplicate_policy().get()
+    }
+
+    /// Get the metadata of a stored argument.
+    #[view(getArgumentMetadata)]
+    fn get_argument_metadata(&self, id: u64) -> ArgumentMetadata<Self::Api> {
+        require!(!self.argument_metadata(id).is_empty(), "Argument not found");
+        self.argument_metadata(id).get()
+    }
+
+    /// Get an argument's metadata and latest text in one call. The text is empty
+    /// for anchored or redacted arguments (see `getArgumentAnchor`, `getRedaction`).
+    #[view(getArgumentFull)]
+    fn get_argument_full(&self, id: u64) -> ArgumentFull<Self::Api> {
+        require!(!self.argument_metadata(id).is_empty(), "Argument not found");
+        ArgumentFull {
+            id,
+            metadata: self.argument_metadata(id).get(),
+            status: self.argument_status(id),
+            text: self.argument_text(id).get(),
+        }
+    }
+
+    /// `getArgumentFull` for several IDs, in the order given. Unknown IDs yield
+    /// None instead of failing the call. At most `MAX_PAGE_SIZE` IDs per call.
+    #[view(getArgumentsFull)]
+    fn get_arguments_full(
+        &self,
+        ids: MultiValueEncoded<u64>,
+    ) -> MultiValueEncoded<Option<ArgumentFull<Self::Api>>> {
+        require!(ids.len() <= MAX_PAGE_SIZE, "Page size exceeds maximum");
+
+        let mut result = MultiValueEncoded::new();
+        for id in ids.into_iter() {
+            if self.argument_metadata(id).is_empty() {
+                result.push(None);
+            } else {
+                result.push(Some(self.get_argument_full(id)));
+            }
+        }
+        result
+    }
+
+    /// Get one version of an argument (1 = original, `getArgumentVersionCount` = latest).
+    #[view(getArgumentVersion)]
+    fn get_argument_version(&self, id: u64, version: usize) -> ArgumentVersion<Self::Api> {
+        require!(
+            self.argument_redaction(id).is_empty(),
+            "Argument has been redacted"
+        );
+        let count = self.get_argument_version_count(id);
+        require!(version >= 1 && version <= count, "Version not found");
+
+        if version < count {
+            return self.argument_versions(id).get(version);
+        }
+        let metadata = self.argument_metadata(id).get();
+        ArgumentVersion {
+            text: self.get_argument(id),
+            quality_score: metadata.quality_score,
+            timestamp: metadata.timestamp,
+        }
+    }
+
+    /// Number of versions of an argument, including the latest.
+    #[view(getArgumentVersionCount)]
+    fn get_argument_version_count(&self, id: u64) -> usize {
+        require!(!self.argument_metadata(id).is_empty(), "Argument not found");
+        self.argument_versions(id).len() + 1
+    }
+
+    /// Get the content hash and off-chain locator of an anchored argument.
+    #[view(getArgumentAnchor)]
+    fn get_argument_anchor(&self, id: u64) -> ArgumentAnchor<Self::Api> {
+        require!(
+            !self.argument_anchor(id).is_empty(),
+            "Argument not anchored"
+        );
+        self.argument_anchor(id).get()
+    }
+
+    /// Check that `text` is the content of an argument. Anchored arguments are
+    /// compared by sha256 hash, full-text arguments byte for byte.
+    #[view(verifyArgument)]
+    fn verify_argument(&self, id: u64, text: ManagedBuffer) -> bool {
+        if !self.argument_redaction(id).is_empty() {
+            let redaction = self.argument_redaction(id).get();
+            return self.crypto().sha256(&text) == redaction.original_hash;
+        }
+        if !self.argument_anchor(id).is_empty() {
+            let anchor = self.argument_anchor(id).get();
+            return self.crypto().sha256(&text) == anchor.content_hash;
+        }
+        require!(!self.argument_text(id).is_empty(), "Argument not found");
+        self.argument_text(id).get() == text
+    }
+
     /// Get all argument IDs for a given debate.
     #[view(getDebateArguments)]
     fn get_debate_arguments(&self, debate_id: u64) -> MultiValueEncoded<u64> {
@@ -144,23 +878,609 @@ pub trait DialecticalPayments: events::EventsModule {
         result
     }
 
+    /// Number of arguments stored in a debate.
+    #[view(getDebateArgumentCount)]
+    fn get_debate_argument_count(&self, debate_id: u64) -> usize {
+        self.debate_arguments(debate_id).len()
+    }
+
+    /// Get up to `count` arguments of a debate, skipping the first `start`
+    /// (in storage order). `count` is limited to `MAX_PAGE_SIZE`.
+    #[view(getDebateArgumentsPage)]
+    fn get_debate_arguments_page(
+        &self,
+        debate_id: u64,
+        start: usize,
+        count: usize,
+    ) -> MultiValueEncoded<ArgumentRecord<Self::Api>> {
+        require!(count <= MAX_PAGE_SIZE, "Page size exceeds maximum");
+        self.argument_records_page(&self.debate_arguments(debate_id), start, count)
+    }
+
+    /// Number of arguments in a debate matching the filter, counted after storage
+    /// position `cursor` (0 to start). `argument_type` of None matches every type;
+    /// `min_quality_score` of 0 matches every score and needs no reads. Otherwise
+    /// at most `MAX_FILTER_SCAN` positions are read per call. Returns the count and
+    /// the position to resume from, which equals the index length once done.
+    #[view(getDebateArgumentCountFiltered)]
+    fn get_debate_argument_count_filtered(
+        &self,
+        debate_id: u64,
+        argument_type: Option<u8>,
+        min_quality_score: u32,
+        cursor: usize,
+    ) -> MultiValue2<usize, usize> {
+        let vec = self.filtered_debate_arguments(debate_id, argument_type);
+        let len = vec.len();
+        if min_quality_score == 0 {
+            return (len.saturating_sub(cursor), len).into();
+        }
+
+        let end = len.min(cursor.saturating_add(MAX_FILTER_SCAN));
+        let mut total = 0;
+        for i in cursor.saturating_add(1)..=end {
+            if self.argument_metadata(vec.get(i)).get().quality_score >= min_quality_score {
+                total += 1;
+            }
+        }
+        (total, end).into()
+    }
+
+    /// Up to `count` arguments matching the filter of `getDebateArgumentCountFiltered`,
+    /// taken after storage position `cursor` (0 for the first page). With a non-zero
+    /// `min_quality_score` at most `MAX_FILTER_SCAN` positions are read per call, so
+    /// a page may come back short. Returns the cursor for the next page, which
+    /// equals the index length once the end is reached, followed by the records.
+    #[view(getDebateArgumentsFiltered)]
+    fn get_debate_arguments_filtered(
+        &self,
+        debate_id: u64,
+        argument_type: Option<u8>,
+        min_quality_score: u32,
+        cursor: usize,
+        count: usize,
+    ) -> MultiValue2<usize, MultiValueEncoded<ArgumentRecord<Self::Api>>> {
+        require!(count <= MAX_PAGE_SIZE, "Page size exceeds maximum");
+
+        let vec = self.filtered_debate_arguments(debate_id, argument_type);
+        let len = vec.len();
+        if min_quality_score == 0 {
+            let next = len.min(cursor.saturating_add(count));
+            return (next, self.argument_records_page(&vec, cursor, count)).into();
+        }
+
+        let end = len.min(cursor.saturating_add(MAX_FILTER_SCAN));
+        let mut result = MultiValueEncoded::new();
+        let mut position = cursor;
+        let mut taken = 0;
+        while taken < count && position < end {
+            position += 1;
+            let id = vec.get(position);
+            let metadata = self.argument_metadata(id).get();
+            if metadata.quality_score >= min_quality_score {
+                result.push(self.argument_record(id, metadata));
+                taken += 1;
+            }
+        }
+        (position.min(len), result).into()
+    }
+
+    /// Get the IDs of arguments replying directly to an argument.
+    #[view(getArgumentChildren)]
+    fn get_argument_children(&self, id: u64) -> MultiValueEncoded<u64> {
+        let mut result = MultiValueEncoded::new();
+        let vec = self.argument_children(id);
+        for i in 1..=vec.len() {
+            result.push(vec.get(i));
+        }
+        result
+    }
+
+    /// Get the IDs from an argument up through its ancestors to the tree root,
+    /// starting with `id` itself.
+    #[view(getArgumentPath)]
+    fn get_argument_path(&self, id: u64) -> MultiValueEncoded<u64> {
+        require!(!self.argument_metadata(id).is_empty(), "Argument not found");
+
+        let mut result = MultiValueEncoded::new();
+        let mut current = id;
+        loop {
+            result.push(current);
+            match self.argument_metadata(current).get().parent {
+                Some(link) => current = link.parent_id,
+                None => break,
+            }
+        }
+        result
+    }
+
+    /// Get all argument IDs stored with the given author address.
+    #[view(getAuthorArguments)]
+    fn get_author_arguments(&self, author: ManagedAddress) -> MultiValueEncoded<u64> {
+        let mut result = MultiValueEncoded::new();
+        let vec = self.author_arguments(&author);
+        for i in 1..=vec.len() {
+            result.push(vec.get(i));
+        }
+        result
+    }
+
+    /// A debate's arguments of one type, or all of them if no type is given.
+    fn filtered_debate_arguments(
+        &self,
+        debate_id: u64,
+        argument_type: Option<u8>,
+    ) -> VecMapper<u64> {
+        match argument_type {
+            Some(argument_type) => self.debate_type_arguments(debate_id, argument_type),
+            None => self.debate_arguments(debate_id),
+        }
+    }
+
+    /// Records for the IDs at positions `start..start + count` of `vec`, read directly.
+    fn argument_records_page(
+        &self,
+        vec: &VecMapper<u64>,
+        start: usize,
+        count: usize,
+    ) -> MultiValueEncoded<ArgumentRecord<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let end = vec.len().min(start.saturating_add(count));
+        for i in start.saturating_add(1)..=end {
+            let id = vec.get(i);
+            result.push(self.argument_record(id, self.argument_metadata(id).get()));
+        }
+        result
+    }
+
+    fn argument_record(
+        &self,
+        id: u64,
+        metadata: ArgumentMetadata<Self::Api>,
+    ) -> ArgumentRecord<Self::Api> {
+        ArgumentRecord {
+            id,
+            metadata,
+            status: self.argument_status(id),
+            text_length: self.argument_text(id).raw_byte_length() as u32,
+        }
+    }
+
+    fn argument_status(&self, id: u64) -> ArgumentStatus {
+        if !self.argument_redaction(id).is_empty() {
+            ArgumentStatus::Redacted
+        } else if !self.argument_anchor(id).is_empty() {
+            ArgumentStatus::Anchored
+        } else {
+            ArgumentStatus::Stored
+        }
+    }
+
+    /// The pending upload for `id`, if the caller is the relayer that began it.
+    fn require_pending_owner(&self, id: u64) -> PendingArgument<Self::Api> {
+        self.require_relayer();
+        require!(!self.pending_argument(id).is_empty(), "No pending upload");
+        let pending = self.pending_argument(id).get();
+        require!(
+            pending.relayer == self.blockchain().get_caller(),
+            "Upload belongs to another relayer"
+        );
+        pending
+    }
+
+    /// Shared body of `storeArgument` and `storeReply`.
+    fn store_text_argument(
+        &self,
+        id: u64,
+        debate_id: u64,
+        argument_type: u8,
+        quality_score: u32,
+        full_text: ManagedBuffer,
+        simhash: Option<u64>,
+        parent: Option<ArgumentLink>,
+        author: OptionalValue<ManagedAddress>,
+        author_signature: OptionalValue<MultiValue2<u64, ManagedBuffer>>,
+    ) {
+        self.require_relayer();
+        require!(!full_text.is_empty(), "Text cannot be empty");
+
+        let author = author.into_option().unwrap_or_else(ManagedAddress::zero);
+        let author_verified = match author_signature {
+            OptionalValue::Some(signed) => {
+                require!(!author.is_zero(), "Signed arguments require an author");
+                let (nonce, signature) = signed.into_tuple();
+                self.verify_author_signature(
+                    &author,
+                    id,
+                    debate_id,
+                    argument_type,
+                    &parent,
+                    &full_text,
+                    nonce,
+                    &signature,
+                );
+                true
+            }
+            OptionalValue::None => false,
+        };
+
+        self.record_argument(
+            id,
+            debate_id,
+            argument_type,
+            quality_score,
+            &self.crypto().sha256(&full_text),
+            simhash,
+            parent,
+            author,
+            author_verified,
+        );
+        self.argument_text(id).set(full_text);
+
+        self.argument_stored_event(id, debate_id, argument_type, quality_score);
+    }
+
+    /// Closest earlier fingerprint in a debate as `(id, distance)`, scanning at most
+    /// the scan limit's most recent ones.
+    fn nearest_fingerprint(&self, debate_id: u64, simhash: u64) -> Option<(u64, u32)> {
+        let fingerprints = self.debate_fingerprints(debate_id);
+        let len = fingerprints.len();
+        let limit = self.get_simhash_scan_limit() as usize;
+        let first = if limit >= len { 1 } else { len - limit + 1 };
+
+        let mut nearest: Option<(u64, u32)> = None;
+        for i in (first..=len).rev() {
+            let fingerprint = fingerprints.get(i);
+            let distance = (fingerprint.simhash ^ simhash).count_ones();
+            if nearest.is_none_or(|(_, best)| distance < best) {
+                nearest = Some((fingerprint.id, distance));
+            }
+        }
+        nearest
+    }
+
+    /// Checks shared by every way of storing an argument.
+    fn validate_new_argument(
+        &self,
+        id: u64,
+        debate_id: u64,
+        argument_type: u8,
+        quality_score: u32,
+    ) {
+        self.require_not_paused(PAUSE_ARGUMENTS);
+        require!(!self.debates(debate_id).is_empty(), "Debate not found");
+        require!(
+            self.debates(debate_id).get().state == DebateState::Open,
+            "Debate is not open"
+        );
+        require!(argument_type <= 2, "Invalid argument type (0-2)");
+        require!(quality_score <= 10_000, "Quality score must be 0-10000");
+        require!(
+            self.argument_metadata(id).is_empty() && self.pending_argument(id).is_empty(),
+            "Argument ID already exists"
+        );
+    }
+
+    /// The earlier argument with byte-identical content, if any. Indexes `content_hash`
+    /// under `id` when it is new; fails if the debate's policy rejects duplicates.
+    fn check_duplicate_content(
+        &self,
+        id: u64,
+        debate_id: u64,
+        content_hash: &ManagedByteArray<32>,
+    ) -> Option<u64> {
+        let original = self.content_hash_argument(content_hash).get();
+        if original == 0 {
+            self.content_hash_argument(content_hash).set(id);
+            return None;
+        }
+        if original == id {
+            return None;
+        }
+        require!(
+            self.get_duplicate_policy(debate_id) == DuplicatePolicy::Mark,
+            "Duplicate argument content"
+        );
+        Some(original)
+    }
+
+    /// The fingerprinted argument closer than the SimHash threshold, if any;
+    /// fails if the debate's policy rejects duplicates.
+    fn check_near_duplicate(&self, debate_id: u64, simhash: u64) -> Option<u64> {
+        let threshold = self.simhash_threshold().get();
+        if threshold == 0 {
+            return None;
+        }
+        let (match_id, _) = self
+            .nearest_fingerprint(debate_id, simhash)
+            .filter(|&(_, distance)| distance < threshold)?;
+        require!(
+            self.get_duplicate_policy(debate_id) == DuplicatePolicy::Mark,
+            "Argument is a near-duplicate"
+        );
+        Some(match_id)
+    }
+
+    /// Validate and write the metadata shared by full-text and anchored arguments.
+    /// A non-zero `author` must have an active subscription; the argument counts
+    /// against their quota and is indexed under their address. `content_hash` is
+    /// checked against earlier arguments per the debate's duplicate policy.
+    /// Counts against the calling relayer's daily cap.
+    fn record_argument(
+        &self,
+        id: u64,
+        debate_id: u64,
+        argument_type: u8,
+        quality_score: u32,
+        content_hash: &ManagedByteArray<32>,
+        simhash: Option<u64>,
+        parent: Option<ArgumentLink>,
+        author: ManagedAddress,
+        author_verified: bool,
+    ) {
+        self.validate_new_argument(id, debate_id, argument_type, quality_score);
+
+        let depth = match &parent {
+            Some(link) => {
+                require!(link.relation <= 2, "Invalid relation (0-2)");
+                require!(
+                    !self.argument_metadata(link.parent_id).is_empty(),
+                    "Parent argument not found"
+                );
+                let parent_metadata = self.argument_metadata(link.parent_id).get();
+                require!(
+                    parent_metadata.debate_id == debate_id,
+                    "Parent argument belongs to another debate"
+                );
+                require!(
+                    parent_metadata.depth < MAX_TREE_DEPTH,
+                    "Maximum tree depth exceeded"
+                );
+                self.argument_children(link.parent_id).push(&id);
+                parent_metadata.depth + 1
+            }
+            None => 0,
+        };
+
+        if argument_type == ARGUMENT_TYPE_THESIS {
+            require!(parent.is_none(), "Thesis cannot be a reply");
+            self.debates(debate_id).update(|debate| {
+                require!(debate.thesis_id.is_none(), "Debate already has a thesis");
+                debate.thesis_id = Some(id);
+            });
+        }
+
+        if !author.is_zero() {
+            self.consume_quota_for(&author, 1);
+            self.author_arguments(&author).push(&id);
+        }
+
+        let duplicate_of = self.check_duplicate_content(id, debate_id, content_hash);
+        let near_duplicate_of = simhash.and_then(|simhash| {
+            let near_duplicate = self.check_near_duplicate(debate_id, simhash);
+            self.debate_fingerprints(debate_id)
+                .push(&ArgumentFingerprint { id, simhash });
+            near_duplicate
+        });
+
+        self.record_relayer_usage();
+        let now = self.blockchain().get_block_timestamp();
+
+        let metadata = ArgumentMetadata {
+            debate_id,
+            argument_type,
+            quality_score,
+            author,
+            relayer: self.blockchain().get_caller(),
+            timestamp: now,
+            author_verified,
+            parent,
+            depth,
+            duplicate_of,
+            near_duplicate_of,
+        };
+
+        self.argument_metadata(id).set(metadata);
+        self.debate_arguments(debate_id).push(&id);
+        self.debate_type_arguments(debate_id, argument_type)
+            .push(&id);
+    }
+
+    // ========================================================================
+    // Moderation endpoints
+    // ========================================================================
+
+    /// Redact an argument's content, leaving a tombstone. The text (including all
+    /// earlier versions) or off-chain locator is erased; the sha256 of every version
+    /// and a reason code are kept so provenance can still be proven.
+    /// Only callable by a moderator.
+    #[endpoint(redactArgument)]
+    fn redact_argument(&self, id: u64, reason_code: u8) {
+        self.require_role(ROLE_MODERATOR);
+        let caller = self.blockchain().get_caller();
+        require!(!self.argument_metadata(id).is_empty(), "Argument not found");
+        require!(
+            self.argument_redaction(id).is_empty(),
+            "Argument already redacted"
+        );
+
+        let mut version_hashes = ManagedVec::new();
+        let original_hash = if self.argument_anchor(id).is_empty() {
+            let text = self.argument_text(id).take();
+            let mut versions = self.argument_versions(id);
+            for i in 1..=versions.len() {
+                let mut version = versions.get(i);
+                version_hashes.push(self.crypto().sha256(&version.text));
+                version.text = ManagedBuffer::new();
+                versions.set(i, &version);
+            }
+            self.crypto().sha256(&text)
+        } else {
+            let mut anchor = self.argument_anchor(id).get();
+            anchor.locator = ManagedBuffer::new();
+            self.argument_anchor(id).set(&anchor);
+            anchor.content_hash
+        };
+
+        self.argument_redaction(id).set(ArgumentRedaction {
+            original_hash,
+            version_hashes,
+            reason_code,
+            moderator: caller.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+        self.argument_redacted_event(id, &caller, reason_code);
+    }
+
+    /// Get the tombstone of a redacted argument.
+    #[view(getRedaction)]
+    fn get_redaction(&self, id: u64) -> ArgumentRedaction<Self::Api> {
+        require!(
+            !self.argument_redaction(id).is_empty(),
+            "Argument not redacted"
+        );
+        self.argument_redaction(id).get()
+    }
+
+    /// Get all moderator addresses.
+    #[view(getModerators)]
+    fn get_moderators(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.role_members(ROLE_MODERATOR).iter().collect()
+    }
+
     // ========================================================================
-    // Owner-only admin endpoints
+    // Admin endpoints
     // ========================================================================
 
-    /// Set the relayer address. Only the contract owner can call this.
-    #[only_owner]
-    #[endpoint(setRelayer)]
-    fn set_relayer(&self, address: ManagedAddress) {
-        self.relayer().set(address);
+    /// Set the global duplicate content policy (default `Mark`).
+    /// Only callable by an admin.
+    #[endpoint(setDuplicatePolicy)]
+    fn set_duplicate_policy(&self, policy: DuplicatePolicy) {
+        self.require_role(ROLE_ADMIN);
+        let old_policy = if self.duplicate_policy().is_empty() {
+            DuplicatePolicy::Mark
+        } else {
+            self.duplicate_policy().get()
+        };
+        self.duplicate_policy().set(policy);
+        self.duplicate_policy_changed_event(old_policy, policy);
     }
 
-    /// Set the EGLD price for a subscription tier. Only the contract owner can call this.
-    #[only_owner]
+    /// Override the duplicate content policy for one debate, or clear the override
+    /// when no policy is given. Only callable by an admin.
+    #[endpoint(setDebateDuplicatePolicy)]
+    fn set_debate_duplicate_policy(&self, debate_id: u64, policy: OptionalValue<DuplicatePolicy>) {
+        self.require_role(ROLE_ADMIN);
+        require!(!self.debates(debate_id).is_empty(), "Debate not found");
+        let old_policy = if self.debate_duplicate_policy(debate_id).is_empty() {
+            None
+        } else {
+            Some(self.debate_duplicate_policy(debate_id).get())
+        };
+        let new_policy = policy.into_option();
+        match new_policy {
+            Some(policy) => self.debate_duplicate_policy(debate_id).set(policy),
+            None => self.debate_duplicate_policy(debate_id).clear(),
+        }
+        self.debate_duplicate_policy_changed_event(debate_id, old_policy, new_policy);
+    }
+
+    /// Tune SimHash near-duplicate detection. Fingerprints closer than `threshold`
+    /// bits count as near-duplicates (0 = detection off); at most `scan_limit`
+    /// (1 to `MAX_SIMHASH_SCAN_LIMIT`) of the most recent fingerprints in a debate
+    /// are compared. Only callable by an admin.
+    #[endpoint(setSimhashSettings)]
+    fn set_simhash_settings(&self, threshold: u32, scan_limit: u32) {
+        self.require_role(ROLE_ADMIN);
+        require!(threshold <= 64, "Threshold must be 0-64");
+        require!(
+            (1..=MAX_SIMHASH_SCAN_LIMIT).contains(&scan_limit),
+            "Scan limit must be 1-1000"
+        );
+        let old_threshold = self.simhash_threshold().replace(threshold);
+        let old_scan_limit = self.get_simhash_scan_limit();
+        self.simhash_scan_limit().set(scan_limit);
+        self.simhash_settings_changed_event(old_threshold, threshold, old_scan_limit, scan_limit);
+    }
+
+    /// Most recent fingerprints in a debate compared per store.
+    fn get_simhash_scan_limit(&self) -> u32 {
+        if self.simhash_scan_limit().is_empty() {
+            return DEFAULT_SIMHASH_SCAN_LIMIT;
+        }
+        self.simhash_scan_limit().get()
+    }
+
+    /// Set the EGLD price for a subscription tier. Only callable by a pricing manager.
+    /// Timelocked: queue it while the timelock delay is non-zero.
     #[endpoint(setTierPricing)]
     fn set_tier_pricing(&self, tier: u8, price: BigUint) {
+        self.require_role(ROLE_PRICING_MANAGER);
+        self.require_not_timelocked();
+        self.apply_tier_pricing(tier, price);
+    }
+
+    /// Set the monthly argument quota for a tier (0 = unlimited).
+    /// Only callable by a pricing manager.
+    #[endpoint(setTierQuota)]
+    fn set_tier_quota(&self, tier: u8, quota: u64) {
+        self.require_role(ROLE_PRICING_MANAGER);
+        require!(tier >= 1 && tier <= MAX_TIER, "Invalid tier (1-3)");
+        let old_quota = self.tier_quota(tier).replace(quota);
+        self.tier_quota_changed_event(tier, old_quota, quota);
+    }
+
+    /// Queue an admin action and return its ID. Requires the same role (or ownership)
+    /// as calling the action's endpoint directly. Actions that `executeQueued` would
+    /// reject for their arguments alone fail here instead of after the delay.
+    #[endpoint(queueAction)]
+    fn queue_action(&self, action: AdminAction<Self::Api>) -> u64 {
+        match &action {
+            AdminAction::SetTierPricing(tier, _) => {
+                require!((1..=MAX_TIER).contains(tier), "Invalid tier (1-3)")
+            }
+            AdminAction::AddRelayer(address, label, _) => {
+                self.validate_relayer_entry(address, label)
+            }
+            AdminAction::SetTimelockDelay(delay) => self.validate_timelock_delay(*delay),
+            AdminAction::SetPayoutAddress(address) => self.validate_payout_address(address),
+            AdminAction::SetWithdrawalLimits(..) => {}
+            AdminAction::SetRevenueSplit(shares) => self.validate_revenue_split(shares),
+        }
+        self.enqueue_action(action)
+    }
+
+    /// Apply a queued admin action once its timelock delay has passed. Requires the
+    /// same role (or ownership) as calling the action's endpoint directly.
+    #[endpoint(executeQueued)]
+    fn execute_queued(&self, id: u64) {
+        match self.take_ready_action(id) {
+            AdminAction::SetTierPricing(tier, price) => self.apply_tier_pricing(tier, price),
+            AdminAction::AddRelayer(address, label, daily_cap) => {
+                self.set_relayer_entry(address, label, daily_cap)
+            }
+            AdminAction::SetTimelockDelay(delay) => self.apply_timelock_delay(delay),
+            AdminAction::SetPayoutAddress(address) => self.apply_payout_address(address),
+            AdminAction::SetWithdrawalLimits(max_per_call, max_per_day) => {
+                self.apply_withdrawal_limits(max_per_call, max_per_day)
+            }
+            AdminAction::SetRevenueSplit(shares) => self.apply_revenue_split(&shares),
+        }
+    }
+
+    /// Set a tier's price and record the change in its price history.
+    fn apply_tier_pricing(&self, tier: u8, price: BigUint) {
         require!(tier >= 1 && tier <= MAX_TIER, "Invalid tier (1-3)");
-        self.tier_pricing(tier).set(price);
+        let old_price = self.tier_pricing(tier).replace(price.clone());
+        let timestamp = self.blockchain().get_block_timestamp();
+
+        self.tier_price_changed_event(tier, &old_price, &price, timestamp);
+        self.tier_price_history(tier).push(&PriceChange {
+            tier,
+            old_price,
+            new_price: price,
+            timestamp,
+        });
     }
 
     // ========================================================================
@@ -175,19 +1495,94 @@ pub trait DialecticalPayments: events::EventsModule {
     #[storage_mapper("argumentMetadata")]
     fn argument_metadata(&self, id: u64) -> SingleValueMapper<ArgumentMetadata<Self::Api>>;
 
+    /// Superseded versions of a revised argument, oldest first. The latest version
+    /// lives in `argument_text` and `argument_metadata`.
+    #[storage_mapper("argumentVersions")]
+    fn argument_versions(&self, id: u64) -> VecMapper<ArgumentVersion<Self::Api>>;
+
+    /// Tombstones of redacted arguments.
+    #[storage_mapper("argumentRedaction")]
+    fn argument_redaction(&self, id: u64) -> SingleValueMapper<ArgumentRedaction<Self::Api>>;
+
+    /// Content hash and off-chain locator for arguments stored via `anchorArgument`.
+    #[storage_mapper("argumentAnchor")]
+    fn argument_anchor(&self, id: u64) -> SingleValueMapper<ArgumentAnchor<Self::Api>>;
+
+    /// SimHash fingerprints of a debate's arguments, in storage order.
+    #[storage_mapper("debateFingerprints")]
+    fn debate_fingerprints(&self, debate_id: u64) -> VecMapper<ArgumentFingerprint>;
+
+    /// Hamming distance below which fingerprints are near-duplicates (0 = off).
+    #[storage_mapper("simhashThreshold")]
+    fn simhash_threshold(&self) -> SingleValueMapper<u32>;
+
+    /// Most recent fingerprints compared per store (`DEFAULT_SIMHASH_SCAN_LIMIT` if empty).
+    #[storage_mapper("simhashScanLimit")]
+    fn simhash_scan_limit(&self) -> SingleValueMapper<u32>;
+
+    /// sha256 of argument content -> first argument stored with it.
+    #[storage_mapper("contentHashArgument")]
+    fn content_hash_argument(&self, content_hash: &ManagedByteArray<32>) -> SingleValueMapper<u64>;
+
+    /// Global duplicate content policy (`Mark` if empty).
+    #[storage_mapper("duplicatePolicy")]
+    fn duplicate_policy(&self) -> SingleValueMapper<DuplicatePolicy>;
+
+    /// Per-debate duplicate content policy, overriding the global one.
+    #[storage_mapper("debateDuplicatePolicy")]
+    fn debate_duplicate_policy(&self, debate_id: u64) -> SingleValueMapper<DuplicatePolicy>;
+
+    /// Next candidate ID for `storeArgumentAuto` (IDs start at 1).
+    #[storage_mapper("nextArgumentId")]
+    fn next_argument_id(&self) -> SingleValueMapper<u64>;
+
+    /// App-side external ID -> contract-assigned argument ID with its debate and content hash.
+    #[storage_mapper("externalArgument")]
+    fn external_argument(&self, external_id: u64)
+    -> SingleValueMapper<ExternalArgument<Self::Api>>;
+
+    /// Declared parameters of chunked uploads not yet finalized.
+    #[storage_mapper("pendingArgument")]
+    fn pending_argument(&self, id: u64) -> SingleValueMapper<PendingArgument<Self::Api>>;
+
+    /// Text received so far for a chunked upload.
+    #[storage_mapper("pendingArgumentText")]
+    fn pending_argument_text(&self, id: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Registered debates, keyed by app-assigned debate ID.
+    #[storage_mapper("debates")]
+    fn debates(&self, debate_id: u64) -> SingleValueMapper<Debate<Self::Api>>;
+
     /// Argument IDs belonging to a debate. Append-only, iterable.
     #[storage_mapper("debateArguments")]
     fn debate_arguments(&self, debate_id: u64) -> VecMapper<u64>;
 
+    /// Argument IDs of one type within a debate, for filtered pagination.
+    /// Append-only, iterable.
+    #[storage_mapper("debateTypeArguments")]
+    fn debate_type_arguments(&self, debate_id: u64, argument_type: u8) -> VecMapper<u64>;
+
+    /// Direct replies to an argument. Append-only, iterable.
+    #[storage_mapper("argumentChildren")]
+    fn argument_children(&self, id: u64) -> VecMapper<u64>;
+
+    /// Argument IDs attributed to an author wallet. Append-only, iterable.
+    #[storage_mapper("authorArguments")]
+    fn author_arguments(&self, author: &ManagedAddress) -> VecMapper<u64>;
+
     /// Subscription info per user address.
     #[storage_mapper("subscriptions")]
     fn subscriptions(&self, user: &ManagedAddress) -> SingleValueMapper<SubscriptionInfo>;
 
-    /// Trusted relayer address (meta-transaction signer).
-    #[storage_mapper("relayer")]
-    fn relayer(&self) -> SingleValueMapper<ManagedAddress>;
+    /// Append-only history of each tier's price changes.
+    #[storage_mapper("tierPriceHistory")]
+    fn tier_price_history(&self, tier: u8) -> VecMapper<PriceChange<Self::Api>>;
 
-    /// EGLD price per tier (set by owner, updatable without upgrade).
+    /// EGLD price per tier (set by a pricing manager, updatable without upgrade).
     #[storage_mapper("tierPricing")]
     fn tier_pricing(&self, tier: u8) -> SingleValueMapper<BigUint>;
+
+    /// Arguments allowed per billing period per tier (0 = unlimited).
+    #[storage_mapper("tierQuota")]
+    fn tier_quota(&self, tier: u8) -> SingleValueMapper<u64>;
 }
@@ -0,0 +1,99 @@
+use multiversx_sc::imports::*;
+
+use crate::events;
+
+/// Ownership — two-step handover of the contract's admin ownership.
+///
+/// The current owner proposes a successor, who must accept from their own wallet,
+/// so ownership can never land on a mistyped or unreachable address. Until the
+/// first handover the owner is the protocol owner (the deployer).
+///
+/// The protocol owner can upgrade the contract, so a handover must move that too.
+/// Before the successor accepts, the protocol owner hands protocol ownership to the
+/// contract itself with the `ChangeOwnerAddress` builtin; `acceptOwnership` then
+/// passes it on to the successor. Nobody can upgrade in between, and the old key
+/// keeps no rights afterwards.
+#[multiversx_sc::module]
+pub trait OwnershipModule: events::EventsModule {
+    /// Propose `new_owner` as the next owner, replacing any earlier proposal.
+    /// Only the contract owner can call this.
+    #[endpoint(proposeOwner)]
+    fn propose_owner(&self, new_owner: ManagedAddress) {
+        self.require_owner();
+        require!(!new_owner.is_zero(), "Owner address cannot be zero");
+        // Keep the owner fixed while protocol ownership is parked in the contract
+        if self.contract_owner().is_empty() {
+            self.contract_owner().set(self.owner());
+        }
+        self.pending_owner().set(&new_owner);
+        self.ownership_proposed_event(&self.owner(), &new_owner);
+    }
+
+    /// Accept a pending ownership proposal and take over protocol ownership, which
+    /// must already have been handed to the contract. Only callable by the proposed owner.
+    #[endpoint(acceptOwnership)]
+    fn accept_ownership(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.pending_owner().is_empty() && self.pending_owner().get() == caller,
+            "Caller is not the pending owner"
+        );
+        let sc_address = self.blockchain().get_sc_address();
+        require!(
+            self.blockchain().get_owner_address() == sc_address,
+            "Protocol ownership must be handed to the contract first"
+        );
+
+        let previous_owner = self.owner();
+        self.pending_owner().clear();
+        self.contract_owner().set(&caller);
+        self.send()
+            .change_owner_address(sc_address, &caller)
+            .sync_call();
+        self.ownership_transferred_event(&previous_owner, &caller);
+    }
+
+    /// The current owner.
+    #[view(getOwner)]
+    fn owner(&self) -> ManagedAddress {
+        if self.contract_owner().is_empty() {
+            return self.blockchain().get_owner_address();
+        }
+        self.contract_owner().get()
+    }
+
+    /// The protocol owner, who can upgrade the contract. Matches `getOwner` once a
+    /// handover completes, and is the contract itself while one is in progress.
+    #[view(getProtocolOwner)]
+    fn get_protocol_owner(&self) -> ManagedAddress {
+        self.blockchain().get_owner_address()
+    }
+
+    /// The proposed next owner, or the zero address if there is no pending proposal.
+    #[view(getPendingOwner)]
+    fn get_pending_owner(&self) -> ManagedAddress {
+        if self.pending_owner().is_empty() {
+            return ManagedAddress::zero();
+        }
+        self.pending_owner().get()
+    }
+
+    fn require_owner(&self) {
+        require!(
+            self.blockchain().get_caller() == self.owner(),
+            "Endpoint can only be called by owner"
+        );
+    }
+
+    // ========================================================================
+    // Storage mappers
+    // ========================================================================
+
+    /// Owner set by `acceptOwnership` (protocol owner if empty).
+    #[storage_mapper("contractOwner")]
+    fn contract_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Owner proposed by `proposeOwner`, awaiting acceptance.
+    #[storage_mapper("pendingOwner")]
+    fn pending_owner(&self) -> SingleValueMapper<ManagedAddress>;
+}
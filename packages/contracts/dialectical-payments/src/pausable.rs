@@ -0,0 +1,74 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events, ownership,
+    roles::{self, ROLE_PAUSER},
+};
+
+pub const PAUSE_SUBSCRIPTIONS: u8 = 1 << 0;
+pub const PAUSE_ARGUMENTS: u8 = 1 << 1;
+pub const PAUSE_TREASURY: u8 = 1 << 2;
+const ALL_GROUPS: u8 = PAUSE_SUBSCRIPTIONS | PAUSE_ARGUMENTS | PAUSE_TREASURY;
+
+/// Pausable — emergency stop for groups of endpoints during an incident.
+///
+/// Groups are bitflags: subscriptions (subscribe, changeTier, cancelSubscription,
+/// consumeQuota), argument storage (debates and every way of storing or revising
+/// arguments) and treasury operations (withdraw, claimRevenue). Views are never paused.
+#[multiversx_sc::module]
+pub trait PausableModule:
+    events::EventsModule + ownership::OwnershipModule + roles::RolesModule
+{
+    /// Pause the endpoint groups in `groups`. Only callable by a pauser.
+    #[endpoint(pause)]
+    fn pause(&self, groups: u8) {
+        self.require_role(ROLE_PAUSER);
+        require!(
+            groups != 0 && groups & !ALL_GROUPS == 0,
+            "Invalid pause groups"
+        );
+        let old_mask = self.paused_groups().get();
+        let new_mask = old_mask | groups;
+        self.paused_groups().set(new_mask);
+        self.paused_event(old_mask, new_mask, &self.blockchain().get_caller());
+    }
+
+    /// Resume the endpoint groups in `groups`. Only callable by a pauser.
+    #[endpoint(unpause)]
+    fn unpause(&self, groups: u8) {
+        self.require_role(ROLE_PAUSER);
+        require!(
+            groups != 0 && groups & !ALL_GROUPS == 0,
+            "Invalid pause groups"
+        );
+        let old_mask = self.paused_groups().get();
+        let new_mask = old_mask & !groups;
+        self.paused_groups().set(new_mask);
+        self.unpaused_event(old_mask, new_mask, &self.blockchain().get_caller());
+    }
+
+    /// Bitflags of the currently paused endpoint groups.
+    #[view(getPausedGroups)]
+    fn get_paused_groups(&self) -> u8 {
+        self.paused_groups().get()
+    }
+
+    fn require_not_paused(&self, group: u8) {
+        if self.paused_groups().get() & group == 0 {
+            return;
+        }
+        match group {
+            PAUSE_SUBSCRIPTIONS => sc_panic!("Subscriptions are paused"),
+            PAUSE_ARGUMENTS => sc_panic!("Argument storage is paused"),
+            _ => sc_panic!("Treasury operations are paused"),
+        }
+    }
+
+    // ========================================================================
+    // Storage mappers
+    // ========================================================================
+
+    /// Bitflags of paused endpoint groups.
+    #[storage_mapper("pausedGroups")]
+    fn paused_groups(&self) -> SingleValueMapper<u8>;
+}
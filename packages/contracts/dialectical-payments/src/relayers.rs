@@ -0,0 +1,126 @@
+use multiversx_sc::{derive_imports::*, imports::*};
+
+use crate::{
+    events, ownership,
+    roles::{self, ROLE_ADMIN, ROLE_RELAYER},
+    timelock,
+    treasury::SECONDS_PER_DAY,
+};
+
+/// Settings and daily usage of a whitelisted relayer.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct RelayerInfo<M: multiversx_sc::api::ManagedTypeApi> {
+    /// Human-readable name, e.g. the backend region.
+    pub label: ManagedBuffer<M>,
+    /// Maximum arguments stored per UTC day (0 = unlimited).
+    pub daily_cap: u64,
+    /// Day index (timestamp / 86400) that `stored_today` refers to.
+    pub day: u64,
+    /// Arguments stored so far during `day`.
+    pub stored_today: u64,
+}
+
+/// Relayer whitelist — the backend keys allowed to store arguments.
+///
+/// Several relayers can be active at once, so keys can be rotated or run in
+/// several regions without downtime. Each relayer has a label and an optional
+/// daily cap on stored arguments. Whitelisted relayers hold the Relayer role.
+#[multiversx_sc::module]
+pub trait RelayersModule:
+    events::EventsModule + ownership::OwnershipModule + roles::RolesModule + timelock::TimelockModule
+{
+    /// Whitelist a relayer, or update the label and daily cap (0 = unlimited) of an
+    /// existing one. Only callable by an admin. Timelocked: queue it while the
+    /// timelock delay is non-zero.
+    #[endpoint(addRelayer)]
+    fn add_relayer(&self, address: ManagedAddress, label: ManagedBuffer, daily_cap: u64) {
+        self.require_role(ROLE_ADMIN);
+        self.require_not_timelocked();
+        self.set_relayer_entry(address, label, daily_cap);
+    }
+
+    /// Remove a relayer from the whitelist. Only callable by an admin.
+    #[endpoint(removeRelayer)]
+    fn remove_relayer(&self, address: ManagedAddress) {
+        self.require_role(ROLE_ADMIN);
+        require!(self.is_relayer(&address), "Not a relayer");
+        self.revoke(&address, ROLE_RELAYER);
+        let info = self.relayer_info(&address).take();
+        self.relayer_removed_event(&address, &info.label, info.daily_cap);
+    }
+
+    /// All whitelisted relayers with their settings and today's usage.
+    #[view(getRelayers)]
+    fn get_relayers(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, RelayerInfo<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for address in self.role_members(ROLE_RELAYER).iter() {
+            let info = self.relayer_info(&address).get();
+            result.push((address, info).into());
+        }
+        result
+    }
+
+    /// Whitelist a relayer or update its label and daily cap.
+    fn set_relayer_entry(&self, address: ManagedAddress, label: ManagedBuffer, daily_cap: u64) {
+        self.validate_relayer_entry(&address, &label);
+
+        let mut info = if self.is_relayer(&address) {
+            self.relayer_info(&address).get()
+        } else {
+            self.grant(&address, ROLE_RELAYER);
+            RelayerInfo {
+                label: ManagedBuffer::new(),
+                daily_cap: 0,
+                day: 0,
+                stored_today: 0,
+            }
+        };
+
+        self.relayer_updated_event(&address, &info.label, &label, info.daily_cap, daily_cap);
+        info.label = label;
+        info.daily_cap = daily_cap;
+        self.relayer_info(&address).set(info);
+    }
+
+    fn validate_relayer_entry(&self, address: &ManagedAddress, label: &ManagedBuffer) {
+        require!(!address.is_zero(), "Relayer address cannot be zero");
+        require!(!label.is_empty(), "Label cannot be empty");
+    }
+
+    fn is_relayer(&self, address: &ManagedAddress) -> bool {
+        self.holds_role(address, ROLE_RELAYER)
+    }
+
+    fn require_relayer(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.is_relayer(&caller), "Only relayer can store arguments");
+    }
+
+    /// Count one stored argument against the calling relayer's daily cap.
+    fn record_relayer_usage(&self) {
+        let caller = self.blockchain().get_caller();
+        let today = self.blockchain().get_block_timestamp() / SECONDS_PER_DAY;
+        self.relayer_info(&caller).update(|info| {
+            if info.day != today {
+                info.day = today;
+                info.stored_today = 0;
+            }
+            info.stored_today += 1;
+            require!(
+                info.daily_cap == 0 || info.stored_today <= info.daily_cap,
+                "Relayer daily cap reached"
+            );
+        });
+    }
+
+    // ========================================================================
+    // Storage mappers
+    // ========================================================================
+
+    /// Label, daily cap and usage per relayer.
+    #[storage_mapper("relayerInfo")]
+    fn relayer_info(&self, address: &ManagedAddress) -> SingleValueMapper<RelayerInfo<Self::Api>>;
+}
@@ -0,0 +1,177 @@
+use multiversx_sc::{derive_imports::*, imports::*};
+
+use crate::{
+    events, ownership,
+    pausable::{self, PAUSE_TREASURY},
+    roles, timelock,
+};
+
+/// Basis points representing 100%.
+pub const TOTAL_SHARE_BPS: u32 = 10_000;
+
+/// A payee and its share of each payment in basis points.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct RevenueShare<M: multiversx_sc::api::ManagedTypeApi> {
+    pub payee: ManagedAddress<M>,
+    pub share_bps: u32,
+}
+
+/// Revenue split — credits a fixed share of each subscription payment to payees.
+///
+/// Shares are in basis points and are applied when a payment arrives, so changing
+/// the split only affects future payments. Anything not assigned to a payee
+/// (including rounding dust) stays in the treasury. Changing the split is
+/// timelocked while the timelock delay is non-zero.
+#[multiversx_sc::module]
+pub trait RevenueSplitModule:
+    events::EventsModule
+    + ownership::OwnershipModule
+    + roles::RolesModule
+    + pausable::PausableModule
+    + timelock::TimelockModule
+{
+    /// Replace the payee list with `(address, share_bps)` pairs.
+    /// Shares must sum to at most 10000. Only the contract owner can call this.
+    /// Timelocked: queue it while the timelock delay is non-zero.
+    #[endpoint(setRevenueSplit)]
+    fn set_revenue_split(&self, payees: MultiValueEncoded<MultiValue2<ManagedAddress, u32>>) {
+        self.require_owner();
+        self.require_not_timelocked();
+
+        let mut shares = ManagedVec::new();
+        for payee in payees.into_iter() {
+            let (payee, share_bps) = payee.into_tuple();
+            shares.push(RevenueShare { payee, share_bps });
+        }
+        self.apply_revenue_split(&shares);
+    }
+
+    /// Claim the caller's accumulated revenue share.
+    #[endpoint(claimRevenue)]
+    fn claim_revenue(&self) {
+        self.require_not_paused(PAUSE_TREASURY);
+        let caller = self.blockchain().get_caller();
+        let amount = self.claimable_revenue(&caller).take();
+        require!(amount > 0u32, "Nothing to claim");
+
+        self.total_claimable_revenue()
+            .update(|total| *total -= &amount);
+        self.total_claimed_revenue()
+            .update(|total| *total += &amount);
+
+        self.send().direct_egld(&caller, &amount);
+        self.revenue_claimed_event(&caller, &amount);
+    }
+
+    /// Current payees and their shares in basis points.
+    #[view(getRevenueSplit)]
+    fn get_revenue_split(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u32>> {
+        let mut result = MultiValueEncoded::new();
+        for (address, share_bps) in self.revenue_shares().iter() {
+            result.push((address, share_bps).into());
+        }
+        result
+    }
+
+    /// Revenue credited to a payee and not yet claimed.
+    #[view(getClaimableRevenue)]
+    fn get_claimable_revenue(&self, payee: ManagedAddress) -> BigUint {
+        self.claimable_revenue(&payee).get()
+    }
+
+    /// Fail unless every payee is non-zero and listed once with a non-zero share,
+    /// and the shares sum to at most `TOTAL_SHARE_BPS`.
+    fn validate_revenue_split(&self, shares: &ManagedVec<RevenueShare<Self::Api>>) {
+        let mut total_bps = 0u32;
+        for (i, share) in shares.iter().enumerate() {
+            require!(!share.payee.is_zero(), "Payee address cannot be zero");
+            require!(share.share_bps > 0, "Payee share must be greater than zero");
+            require!(
+                shares
+                    .iter()
+                    .take(i)
+                    .all(|earlier| earlier.payee != share.payee),
+                "Duplicate payee"
+            );
+            require!(
+                share.share_bps <= TOTAL_SHARE_BPS - total_bps,
+                "Shares exceed 10000 basis points"
+            );
+            total_bps += share.share_bps;
+        }
+    }
+
+    /// Replace the payee list, emitting an event for every share that changed.
+    fn apply_revenue_split(&self, shares: &ManagedVec<RevenueShare<Self::Api>>) {
+        self.validate_revenue_split(shares);
+
+        let mut old_shares = ManagedVec::<Self::Api, RevenueShare<Self::Api>>::new();
+        for (payee, share_bps) in self.revenue_shares().iter() {
+            old_shares.push(RevenueShare { payee, share_bps });
+        }
+        self.revenue_shares().clear();
+
+        let mut total_bps = 0u32;
+        for share in shares.iter() {
+            let (address, share_bps) = (&share.payee, share.share_bps);
+            self.revenue_shares().insert(address.clone(), share_bps);
+            total_bps += share_bps;
+
+            let old_bps = old_shares
+                .iter()
+                .find(|old| old.payee == *address)
+                .map_or(0, |old| old.share_bps);
+            if old_bps != share_bps {
+                self.revenue_share_changed_event(address, old_bps, share_bps);
+            }
+        }
+
+        for old in old_shares.iter() {
+            if !self.revenue_shares().contains_key(&old.payee) {
+                self.revenue_share_changed_event(&old.payee, old.share_bps, 0);
+            }
+        }
+
+        self.revenue_split_updated_event(total_bps);
+    }
+
+    /// Credit each payee's share of a payment to their claimable balance.
+    fn distribute_revenue(&self, amount: &BigUint) {
+        let mut distributed = BigUint::zero();
+        for (address, share_bps) in self.revenue_shares().iter() {
+            let share = amount * share_bps / TOTAL_SHARE_BPS;
+            if share == 0u32 {
+                continue;
+            }
+            self.claimable_revenue(&address)
+                .update(|balance| *balance += &share);
+            distributed += share;
+        }
+
+        if distributed > 0u32 {
+            self.total_claimable_revenue()
+                .update(|total| *total += distributed);
+        }
+    }
+
+    // ========================================================================
+    // Storage mappers
+    // ========================================================================
+
+    /// Payee address -> share of each payment in basis points.
+    #[storage_mapper("revenueShares")]
+    fn revenue_shares(&self) -> MapMapper<ManagedAddress, u32>;
+
+    /// Unclaimed revenue per payee.
+    #[storage_mapper("claimableRevenue")]
+    fn claimable_revenue(&self, payee: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Sum of all unclaimed payee balances. Reserved, not withdrawable by the owner.
+    #[storage_mapper("totalClaimableRevenue")]
+    fn total_claimable_revenue(&self) -> SingleValueMapper<BigUint>;
+
+    /// Total revenue claimed by payees over the contract's lifetime.
+    #[storage_mapper("totalClaimedRevenue")]
+    fn total_claimed_revenue(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,109 @@
+use multiversx_sc::imports::*;
+
+use crate::{events, ownership};
+
+pub const ROLE_ADMIN: u8 = 1 << 0;
+pub const ROLE_PRICING_MANAGER: u8 = 1 << 1;
+pub const ROLE_MODERATOR: u8 = 1 << 2;
+pub const ROLE_RELAYER: u8 = 1 << 3;
+pub const ROLE_PAUSER: u8 = 1 << 4;
+const ALL_ROLES: u8 =
+    ROLE_ADMIN | ROLE_PRICING_MANAGER | ROLE_MODERATOR | ROLE_RELAYER | ROLE_PAUSER;
+
+/// Roles — narrow permissions stored as bitflags per address.
+///
+/// Admin manages roles and contract settings, PricingManager sets tier prices and
+/// quotas, Moderator redacts arguments, Relayer stores arguments and Pauser
+/// pauses the contract. The owner implicitly holds every role except Relayer,
+/// which always needs an explicit grant through `addRelayer`. Endpoints that move
+/// funds (treasury, revenue split) stay owner-only.
+#[multiversx_sc::module]
+pub trait RolesModule: events::EventsModule + ownership::OwnershipModule {
+    /// Grant a single role bit to an address. Only callable by an admin.
+    #[endpoint(grantRole)]
+    fn grant_role(&self, address: ManagedAddress, role: u8) {
+        self.require_role(ROLE_ADMIN);
+        require!(role != ROLE_RELAYER, "Use addRelayer for the relayer role");
+        self.grant(&address, role);
+    }
+
+    /// Revoke a single role bit from an address. Only callable by an admin.
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, address: ManagedAddress, role: u8) {
+        self.require_role(ROLE_ADMIN);
+        require!(
+            role != ROLE_RELAYER,
+            "Use removeRelayer for the relayer role"
+        );
+        self.revoke(&address, role);
+    }
+
+    /// Role bits explicitly granted to an address.
+    #[view(getRoles)]
+    fn get_roles(&self, address: ManagedAddress) -> u8 {
+        self.roles(&address).get()
+    }
+
+    /// All addresses explicitly granted a role.
+    #[view(getRoleMembers)]
+    fn get_role_members(&self, role: u8) -> MultiValueEncoded<ManagedAddress> {
+        self.role_members(role).iter().collect()
+    }
+
+    /// True if `address` holds `role`, explicitly or as the owner.
+    fn holds_role(&self, address: &ManagedAddress, role: u8) -> bool {
+        if self.roles(address).get() & role != 0 {
+            return true;
+        }
+        role != ROLE_RELAYER && *address == self.owner()
+    }
+
+    fn require_role(&self, role: u8) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.holds_role(&caller, role),
+            "Caller lacks the required role"
+        );
+    }
+
+    /// Roles are granted and revoked one bit at a time.
+    fn require_valid_role(&self, role: u8) {
+        require!(
+            role.count_ones() == 1 && role & ALL_ROLES != 0,
+            "Invalid role"
+        );
+    }
+
+    fn grant(&self, address: &ManagedAddress, role: u8) {
+        self.require_valid_role(role);
+        require!(!address.is_zero(), "Address cannot be zero");
+        let roles = self.roles(address).get();
+        require!(roles & role == 0, "Role already granted");
+
+        self.roles(address).set(roles | role);
+        self.role_members(role).insert(address.clone());
+        self.role_granted_event(address, role, &self.blockchain().get_caller());
+    }
+
+    fn revoke(&self, address: &ManagedAddress, role: u8) {
+        self.require_valid_role(role);
+        let roles = self.roles(address).get();
+        require!(roles & role != 0, "Role not granted");
+
+        self.roles(address).set(roles & !role);
+        self.role_members(role).swap_remove(address);
+        self.role_revoked_event(address, role, &self.blockchain().get_caller());
+    }
+
+    // ========================================================================
+    // Storage mappers
+    // ========================================================================
+
+    /// Role bitflags per address.
+    #[storage_mapper("roles")]
+    fn roles(&self, address: &ManagedAddress) -> SingleValueMapper<u8>;
+
+    /// Addresses holding each role bit, for enumeration.
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: u8) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -1,4 +1,7 @@
-use multiversx_sc::derive_imports::*;
+use multiversx_sc::{derive_imports::*, imports::*};
+
+/// Length of one billing period.
+pub const SECONDS_PER_30_DAYS: u64 = 30 * 24 * 60 * 60;
 
 /// On-chain subscription state for a user address.
 #[type_abi]
@@ -10,6 +13,19 @@ pub struct SubscriptionInfo {
     pub expires_at: u64,
     /// Number of arguments used in the current billing period.
     pub arguments_used: u64,
+    /// Unix timestamp when the current billing period ends (never after `expires_at`).
+    pub period_ends_at: u64,
+}
+
+/// One entry of a tier's append-only price history, recorded by `setTierPricing`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct PriceChange<M: multiversx_sc::api::ManagedTypeApi> {
+    pub tier: u8,
+    pub old_price: BigUint<M>,
+    pub new_price: BigUint<M>,
+    /// Block timestamp from which `new_price` applies.
+    pub timestamp: u64,
 }
 
 impl SubscriptionInfo {
@@ -17,4 +33,18 @@ impl SubscriptionInfo {
     pub fn is_active(&self, current_timestamp: u64) -> bool {
         self.expires_at > current_timestamp
     }
+
+    /// Moves the billing period forward to the one containing `current_timestamp`.
+    /// Resets `arguments_used` and returns true if a new period has begun.
+    pub fn advance_period(&mut self, current_timestamp: u64) -> bool {
+        if !self.is_active(current_timestamp) || current_timestamp < self.period_ends_at {
+            return false;
+        }
+
+        let periods_elapsed = (current_timestamp - self.period_ends_at) / SECONDS_PER_30_DAYS + 1;
+        let period_ends_at = self.period_ends_at + periods_elapsed * SECONDS_PER_30_DAYS;
+        self.period_ends_at = period_ends_at.min(self.expires_at);
+        self.arguments_used = 0;
+        true
+    }
 }
@@ -0,0 +1,191 @@
+use multiversx_sc::{derive_imports::*, imports::*};
+
+use crate::{
+    events, ownership,
+    revenue_split::RevenueShare,
+    roles::{self, ROLE_ADMIN, ROLE_PRICING_MANAGER},
+};
+
+/// Longest allowed timelock delay, so the delay can never lock admin changes for good.
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// A sensitive admin change that goes through the timelock queue.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum AdminAction<M: multiversx_sc::api::ManagedTypeApi> {
+    /// `setTierPricing(tier, price)`.
+    SetTierPricing(u8, BigUint<M>),
+    /// `addRelayer(address, label, daily_cap)`.
+    AddRelayer(ManagedAddress<M>, ManagedBuffer<M>, u64),
+    /// Change the timelock delay (lowering it always needs the queue).
+    SetTimelockDelay(u64),
+    /// `setPayoutAddress(address)`.
+    SetPayoutAddress(ManagedAddress<M>),
+    /// `setWithdrawalLimits(max_per_call, max_per_day)`.
+    SetWithdrawalLimits(BigUint<M>, BigUint<M>),
+    /// `setRevenueSplit(payees)`.
+    SetRevenueSplit(ManagedVec<M, RevenueShare<M>>),
+}
+
+/// An action waiting in the timelock queue.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct QueuedAction<M: multiversx_sc::api::ManagedTypeApi> {
+    pub action: AdminAction<M>,
+    /// Earliest block timestamp at which `executeQueued` may apply it.
+    pub eta: u64,
+    pub proposer: ManagedAddress<M>,
+}
+
+/// Timelock — delays sensitive admin changes so they can be spotted and cancelled.
+///
+/// While the delay is non-zero, `setTierPricing`, `addRelayer`, `setPayoutAddress`,
+/// `setRevenueSplit` and any loosening of `setWithdrawalLimits` cannot be called
+/// directly: the change is queued with `queueAction`, can be cancelled by an admin
+/// during the delay, and is applied by `executeQueued` once the delay has passed.
+/// A delay of 0 (the default) applies changes immediately; the delay is at most
+/// `MAX_TIMELOCK_DELAY`.
+#[multiversx_sc::module]
+pub trait TimelockModule:
+    events::EventsModule + ownership::OwnershipModule + roles::RolesModule
+{
+    /// Add an admin action to the queue and return its ID. Requires the same role
+    /// (or ownership) as calling the action's endpoint directly.
+    fn enqueue_action(&self, action: AdminAction<Self::Api>) -> u64 {
+        self.require_action_permission(&action);
+
+        let id = self.last_action_id().get() + 1;
+        self.last_action_id().set(id);
+
+        let proposer = self.blockchain().get_caller();
+        let eta = self
+            .blockchain()
+            .get_block_timestamp()
+            .checked_add(self.timelock_delay().get())
+            .unwrap_or_else(|| sc_panic!("Timelock delay overflow"));
+        self.queued_action(id).set(QueuedAction {
+            action,
+            eta,
+            proposer: proposer.clone(),
+        });
+        self.queued_action_ids().insert(id);
+
+        self.action_queued_event(id, eta, &proposer);
+        id
+    }
+
+    /// Drop a queued action before it is executed. Only callable by an admin.
+    #[endpoint(cancelQueued)]
+    fn cancel_queued(&self, id: u64) {
+        self.require_role(ROLE_ADMIN);
+        require!(
+            self.queued_action_ids().swap_remove(&id),
+            "Action not queued"
+        );
+        self.queued_action(id).clear();
+        self.action_cancelled_event(id, &self.blockchain().get_caller());
+    }
+
+    /// Raise the timelock delay in seconds, up to `MAX_TIMELOCK_DELAY`. Takes effect
+    /// immediately; lowering the delay must go through `queueAction`.
+    /// Only callable by an admin.
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, delay: u64) {
+        self.require_role(ROLE_ADMIN);
+        require!(
+            delay >= self.timelock_delay().get(),
+            "Lowering the delay must be queued"
+        );
+        self.apply_timelock_delay(delay);
+    }
+
+    /// Current timelock delay in seconds (0 = changes apply immediately).
+    #[view(getTimelockDelay)]
+    fn get_timelock_delay(&self) -> u64 {
+        self.timelock_delay().get()
+    }
+
+    /// Get a queued action.
+    #[view(getQueuedAction)]
+    fn get_queued_action(&self, id: u64) -> QueuedAction<Self::Api> {
+        require!(self.queued_action_ids().contains(&id), "Action not queued");
+        self.queued_action(id).get()
+    }
+
+    /// IDs of all actions still in the queue.
+    #[view(getQueuedActionIds)]
+    fn get_queued_action_ids(&self) -> MultiValueEncoded<u64> {
+        self.queued_action_ids().iter().collect()
+    }
+
+    /// Fail unless the caller may queue or execute an action. Actions that move
+    /// funds are owner-only, like their endpoints.
+    fn require_action_permission(&self, action: &AdminAction<Self::Api>) {
+        match action {
+            AdminAction::SetTierPricing(..) => self.require_role(ROLE_PRICING_MANAGER),
+            AdminAction::AddRelayer(..) | AdminAction::SetTimelockDelay(..) => {
+                self.require_role(ROLE_ADMIN)
+            }
+            AdminAction::SetPayoutAddress(..)
+            | AdminAction::SetWithdrawalLimits(..)
+            | AdminAction::SetRevenueSplit(..) => self.require_owner(),
+        }
+    }
+
+    fn apply_timelock_delay(&self, delay: u64) {
+        self.validate_timelock_delay(delay);
+        let old_delay = self.timelock_delay().replace(delay);
+        self.timelock_delay_changed_event(old_delay, delay);
+    }
+
+    fn validate_timelock_delay(&self, delay: u64) {
+        require!(
+            delay <= MAX_TIMELOCK_DELAY,
+            "Timelock delay exceeds maximum"
+        );
+    }
+
+    /// Fail unless timelocked actions may be applied directly.
+    fn require_not_timelocked(&self) {
+        require!(
+            self.timelock_delay().get() == 0,
+            "Action is timelocked, use queueAction"
+        );
+    }
+
+    /// Remove an action whose delay has passed from the queue and return it for
+    /// execution. The caller must still hold the action's role (or ownership).
+    fn take_ready_action(&self, id: u64) -> AdminAction<Self::Api> {
+        require!(self.queued_action_ids().contains(&id), "Action not queued");
+        let queued = self.queued_action(id).take();
+        require!(
+            self.blockchain().get_block_timestamp() >= queued.eta,
+            "Timelock delay has not passed"
+        );
+        self.require_action_permission(&queued.action);
+
+        self.queued_action_ids().swap_remove(&id);
+        self.action_executed_event(id, &self.blockchain().get_caller());
+        queued.action
+    }
+
+    // ========================================================================
+    // Storage mappers
+    // ========================================================================
+
+    /// Seconds between queueing an action and when it can be executed.
+    #[storage_mapper("timelockDelay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    /// ID of the most recently queued action (IDs start at 1).
+    #[storage_mapper("lastActionId")]
+    fn last_action_id(&self) -> SingleValueMapper<u64>;
+
+    /// Queued actions by ID.
+    #[storage_mapper("queuedAction")]
+    fn queued_action(&self, id: u64) -> SingleValueMapper<QueuedAction<Self::Api>>;
+
+    /// IDs of actions queued and not yet executed or cancelled.
+    #[storage_mapper("queuedActionIds")]
+    fn queued_action_ids(&self) -> UnorderedSetMapper<u64>;
+}
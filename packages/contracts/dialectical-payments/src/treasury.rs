@@ -0,0 +1,205 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events, ownership,
+    pausable::{self, PAUSE_TREASURY},
+    revenue_split, roles, timelock,
+};
+
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Treasury — moves collected subscription revenue out of the contract.
+///
+/// Withdrawals are owner-only and bounded by optional per-call and per-day caps
+/// (0 = no cap). The daily cap resets at each UTC day boundary. Revenue owed to
+/// payees under the revenue split is reserved and cannot be withdrawn. While the
+/// timelock delay is non-zero, withdrawals only go to the payout address, and
+/// changing it or loosening a cap must be queued, so a stolen owner key cannot
+/// redirect funds before the change is spotted. This relies on an explicitly set
+/// payout address and does not cover upgrades, which the protocol owner controls.
+#[multiversx_sc::module]
+pub trait TreasuryModule:
+    events::EventsModule
+    + ownership::OwnershipModule
+    + roles::RolesModule
+    + pausable::PausableModule
+    + timelock::TimelockModule
+    + revenue_split::RevenueSplitModule
+{
+    /// Withdraw EGLD from the contract. Sends to `to` if given, otherwise to the
+    /// configured payout address (or the owner if none is set). While the timelock
+    /// delay is non-zero, `to` must be the payout address.
+    #[endpoint(withdraw)]
+    fn withdraw(&self, amount: BigUint, to: OptionalValue<ManagedAddress>) {
+        self.require_owner();
+        self.require_not_paused(PAUSE_TREASURY);
+        require!(amount > 0u32, "Amount must be greater than zero");
+
+        let payout_address = self.get_payout_address();
+        let recipient = match to {
+            OptionalValue::Some(address) => {
+                require!(
+                    address == payout_address || self.get_timelock_delay() == 0,
+                    "Recipient must be the payout address while timelocked"
+                );
+                address
+            }
+            OptionalValue::None => payout_address,
+        };
+
+        let max_per_call = self.max_withdrawal_per_call().get();
+        require!(
+            max_per_call == 0u32 || amount <= max_per_call,
+            "Amount exceeds per-call withdrawal limit"
+        );
+
+        require!(
+            amount <= self.get_withdrawable_balance(),
+            "Insufficient withdrawable balance"
+        );
+
+        let now = self.blockchain().get_block_timestamp();
+        let today = now / SECONDS_PER_DAY;
+        if self.withdrawal_day().get() != today {
+            self.withdrawal_day().set(today);
+            self.withdrawn_today().clear();
+        }
+
+        let withdrawn_today = self.withdrawn_today().get() + &amount;
+        let max_per_day = self.max_withdrawal_per_day().get();
+        require!(
+            max_per_day == 0u32 || withdrawn_today <= max_per_day,
+            "Amount exceeds daily withdrawal limit"
+        );
+
+        self.withdrawn_today().set(withdrawn_today);
+        self.total_withdrawn().update(|total| *total += &amount);
+
+        self.send().direct_egld(&recipient, &amount);
+        self.treasury_withdrawn_event(&recipient, &amount, now);
+    }
+
+    /// Set the default recipient for `withdraw`. Only the contract owner can call this.
+    /// Timelocked: queue it while the timelock delay is non-zero.
+    #[endpoint(setPayoutAddress)]
+    fn set_payout_address(&self, address: ManagedAddress) {
+        self.require_owner();
+        self.require_not_timelocked();
+        self.apply_payout_address(address);
+    }
+
+    /// Set the per-call and per-day withdrawal caps (0 = unlimited).
+    /// Only the contract owner can call this. Tightening both caps takes effect
+    /// immediately; otherwise queue it while the timelock delay is non-zero.
+    #[endpoint(setWithdrawalLimits)]
+    fn set_withdrawal_limits(&self, max_per_call: BigUint, max_per_day: BigUint) {
+        self.require_owner();
+        let tightens = self.cap_tightens(&self.max_withdrawal_per_call().get(), &max_per_call)
+            && self.cap_tightens(&self.max_withdrawal_per_day().get(), &max_per_day);
+        if !tightens {
+            self.require_not_timelocked();
+        }
+        self.apply_withdrawal_limits(max_per_call, max_per_day);
+    }
+
+    /// The address `withdraw` pays out to by default. Falls back to the owner.
+    #[view(getPayoutAddress)]
+    fn get_payout_address(&self) -> ManagedAddress {
+        if self.payout_address().is_empty() {
+            return self.owner();
+        }
+        self.payout_address().get()
+    }
+
+    /// Treasury status: (contract balance, lifetime subscription revenue, total withdrawn,
+    /// total claimed by payees, revenue reserved for payees). Balance should equal revenue
+    /// minus withdrawals and claims unless EGLD arrived by other means.
+    #[view(getTreasuryStatus)]
+    fn get_treasury_status(&self) -> MultiValue5<BigUint, BigUint, BigUint, BigUint, BigUint> {
+        let sc_address = self.blockchain().get_sc_address();
+        let balance = self.blockchain().get_balance(&sc_address);
+        (
+            balance,
+            self.lifetime_revenue().get(),
+            self.total_withdrawn().get(),
+            self.total_claimed_revenue().get(),
+            self.total_claimable_revenue().get(),
+        )
+            .into()
+    }
+
+    /// Contract balance minus revenue reserved for payees.
+    #[view(getWithdrawableBalance)]
+    fn get_withdrawable_balance(&self) -> BigUint {
+        let sc_address = self.blockchain().get_sc_address();
+        let balance = self.blockchain().get_balance(&sc_address);
+        let reserved = self.total_claimable_revenue().get();
+        if balance <= reserved {
+            return BigUint::zero();
+        }
+        balance - reserved
+    }
+
+    fn apply_payout_address(&self, address: ManagedAddress) {
+        self.validate_payout_address(&address);
+        self.payout_address_changed_event(&self.get_payout_address(), &address);
+        self.payout_address().set(address);
+    }
+
+    fn validate_payout_address(&self, address: &ManagedAddress) {
+        require!(!address.is_zero(), "Payout address cannot be zero");
+    }
+
+    fn apply_withdrawal_limits(&self, max_per_call: BigUint, max_per_day: BigUint) {
+        let old_max_per_call = self.max_withdrawal_per_call().replace(max_per_call.clone());
+        let old_max_per_day = self.max_withdrawal_per_day().replace(max_per_day.clone());
+        self.withdrawal_limits_changed_event(
+            &old_max_per_call,
+            &max_per_call,
+            &old_max_per_day,
+            &max_per_day,
+        );
+    }
+
+    /// True if `new_cap` allows no more than `old_cap` (0 = unlimited).
+    fn cap_tightens(&self, old_cap: &BigUint, new_cap: &BigUint) -> bool {
+        *new_cap != 0u32 && (*old_cap == 0u32 || new_cap <= old_cap)
+    }
+
+    /// Record a subscription payment in the lifetime revenue counter.
+    fn record_revenue(&self, amount: &BigUint) {
+        self.lifetime_revenue().update(|total| *total += amount);
+    }
+
+    // ========================================================================
+    // Storage mappers
+    // ========================================================================
+
+    /// Default withdrawal recipient (owner if empty).
+    #[storage_mapper("payoutAddress")]
+    fn payout_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Maximum EGLD per `withdraw` call (0 = unlimited).
+    #[storage_mapper("maxWithdrawalPerCall")]
+    fn max_withdrawal_per_call(&self) -> SingleValueMapper<BigUint>;
+
+    /// Maximum EGLD withdrawn per UTC day (0 = unlimited).
+    #[storage_mapper("maxWithdrawalPerDay")]
+    fn max_withdrawal_per_day(&self) -> SingleValueMapper<BigUint>;
+
+    /// Day index (timestamp / 86400) that `withdrawn_today` refers to.
+    #[storage_mapper("withdrawalDay")]
+    fn withdrawal_day(&self) -> SingleValueMapper<u64>;
+
+    /// EGLD withdrawn so far during `withdrawal_day`.
+    #[storage_mapper("withdrawnToday")]
+    fn withdrawn_today(&self) -> SingleValueMapper<BigUint>;
+
+    /// Total EGLD withdrawn over the contract's lifetime.
+    #[storage_mapper("totalWithdrawn")]
+    fn total_withdrawn(&self) -> SingleValueMapper<BigUint>;
+
+    /// Total EGLD received through `subscribe` over the contract's lifetime.
+    #[storage_mapper("lifetimeRevenue")]
+    fn lifetime_revenue(&self) -> SingleValueMapper<BigUint>;
+}
@@ -1,11 +1,27 @@
+use dialectical_payments::dialectical_payments_proxy::{
+    AdminAction, ArgumentStatus, DebateState, DebateVerdict, DuplicatePolicy, RevenueShare,
+};
+use dialectical_payments::pausable::{PAUSE_ARGUMENTS, PAUSE_SUBSCRIPTIONS, PAUSE_TREASURY};
+use dialectical_payments::roles::{
+    ROLE_ADMIN, ROLE_MODERATOR, ROLE_PAUSER, ROLE_PRICING_MANAGER, ROLE_RELAYER,
+};
+use dialectical_payments::timelock::MAX_TIMELOCK_DELAY;
+use ed25519_dalek::{Signer, SigningKey};
 use multiversx_sc_scenario::imports::*;
+use sha2::{Digest, Sha256};
 
 const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
 const RELAYER_ADDRESS: TestAddress = TestAddress::new("relayer");
+const RELAYER_B_ADDRESS: TestAddress = TestAddress::new("relayer_b");
 const USER_ADDRESS: TestAddress = TestAddress::new("user");
 const NON_OWNER_ADDRESS: TestAddress = TestAddress::new("non_owner");
+const PAYOUT_ADDRESS: TestAddress = TestAddress::new("payout");
+const PAYEE_A_ADDRESS: TestAddress = TestAddress::new("payee_a");
+const PAYEE_B_ADDRESS: TestAddress = TestAddress::new("payee_b");
+const MODERATOR_ADDRESS: TestAddress = TestAddress::new("moderator");
 const SC_ADDRESS: TestSCAddress = TestSCAddress::new("dialectical-payments");
 const CODE_PATH: MxscPath = MxscPath::new("output/dialectical-payments.mxsc.json");
+const DAY: u64 = 24 * 60 * 60;
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -13,6 +29,89 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+/// `storeArgument` SimHash argument for arguments stored without a fingerprint.
+fn no_simhash() -> Option<u64> {
+    None
+}
+
+/// `storeArgument` author argument for arguments without a linked wallet.
+fn no_author() -> OptionalValue<ManagedAddress<StaticApi>> {
+    OptionalValue::None
+}
+
+/// `storeArgument` author signature argument for unsigned arguments.
+fn no_signature() -> OptionalValue<MultiValue2<u64, ManagedBuffer<StaticApi>>> {
+    OptionalValue::None
+}
+
+/// The message an author signs for `storeArgument` (`parent` None) or `storeReply`.
+fn author_message(
+    id: u64,
+    debate_id: u64,
+    argument_type: u8,
+    parent: Option<(u64, u8)>,
+    text: &str,
+    nonce: u64,
+) -> Vec<u8> {
+    let (parent_id, relation) = parent.unwrap_or((0, 0xff));
+    let mut message = SC_ADDRESS.to_address().as_bytes().to_vec();
+    message.extend_from_slice(&id.to_be_bytes());
+    message.extend_from_slice(&debate_id.to_be_bytes());
+    message.push(argument_type);
+    message.extend_from_slice(&parent_id.to_be_bytes());
+    message.push(relation);
+    message.extend_from_slice(&Sha256::digest(text.as_bytes()));
+    message.extend_from_slice(&nonce.to_be_bytes());
+    message
+}
+
+/// Deploy with thinker pricing and subscribe USER_ADDRESS once at timestamp 0.
+fn setup_subscribed_user(world: &mut ScenarioWorld, thinker_price: u64) {
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(100_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, thinker_price)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+}
+
+/// Register `debate_id` as an open debate via the relayer.
+fn create_debate(world: &mut ScenarioWorld, debate_id: u64) {
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .create_debate(
+            debate_id,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[debate_id as u8; 32]),
+            no_author(),
+        )
+        .run();
+}
+
 /// Test 1: Subscribe happy path — correct expiry (now + 30 days).
 #[test]
 fn subscribe_happy_path() {
@@ -186,9 +285,11 @@ fn relayer_stores_argument() {
         .from(OWNER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(dialectical_payments::DialecticalPaymentsProxy)
-        .set_relayer(RELAYER_ADDRESS.to_managed_address())
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
         .run();
 
+    create_debate(&mut world, 1u64);
+
     // Store a ~2KB argument
     let text = "A".repeat(2048);
     world
@@ -196,7 +297,7 @@ fn relayer_stores_argument() {
         .from(RELAYER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(dialectical_payments::DialecticalPaymentsProxy)
-        .store_argument(1u64, 1u64, 0u8, 8500u32, text.as_str())
+        .store_argument(1u64, 1u64, 0u8, 8500u32, text.as_str(), no_simhash(), no_author(), no_signature())
         .run();
 
     // Retrieve it
@@ -234,7 +335,7 @@ fn non_relayer_cannot_store() {
         .from(OWNER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(dialectical_payments::DialecticalPaymentsProxy)
-        .set_relayer(RELAYER_ADDRESS.to_managed_address())
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
         .run();
 
     // Non-relayer tries to store
@@ -243,7 +344,7 @@ fn non_relayer_cannot_store() {
         .from(USER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(dialectical_payments::DialecticalPaymentsProxy)
-        .store_argument(1u64, 1u64, 0u8, 7500u32, "Some argument text")
+        .store_argument(1u64, 1u64, 0u8, 7500u32, "Some argument text", no_simhash(), no_author(), no_signature())
         .with_result(ExpectError(4, "Only relayer can store arguments"))
         .run();
 }
@@ -271,9 +372,11 @@ fn store_argument_gas_budget() {
         .from(OWNER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(dialectical_payments::DialecticalPaymentsProxy)
-        .set_relayer(RELAYER_ADDRESS.to_managed_address())
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
         .run();
 
+    create_debate(&mut world, 1u64);
+
     // Store 2KB text with gas limit of 30M (our budget target)
     let text = "B".repeat(2048);
     world
@@ -281,7 +384,7 @@ fn store_argument_gas_budget() {
         .from(RELAYER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(dialectical_payments::DialecticalPaymentsProxy)
-        .store_argument(1u64, 1u64, 1u8, 9200u32, text.as_str())
+        .store_argument(1u64, 1u64, 1u8, 9200u32, text.as_str(), no_simhash(), no_author(), no_signature())
         .gas(30_000_000u64)
         .run();
 }
@@ -308,10 +411,11 @@ fn debate_arguments_tracking() {
         .from(OWNER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(dialectical_payments::DialecticalPaymentsProxy)
-        .set_relayer(RELAYER_ADDRESS.to_managed_address())
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
         .run();
 
     let debate_id = 42u64;
+    create_debate(&mut world, debate_id);
 
     // Store 3 arguments in the same debate
     for i in 1u64..=3 {
@@ -320,7 +424,16 @@ fn debate_arguments_tracking() {
             .from(RELAYER_ADDRESS)
             .to(SC_ADDRESS)
             .typed(dialectical_payments::DialecticalPaymentsProxy)
-            .store_argument(i, debate_id, 0u8, 7000u32, format!("Argument {}", i).as_str())
+            .store_argument(
+                i,
+                debate_id,
+                0u8,
+                7000u32,
+                format!("Argument {}", i).as_str(),
+                no_simhash(),
+                no_author(),
+                no_signature(),
+            )
             .run();
     }
 
@@ -395,9 +508,9 @@ fn cancel_subscription() {
         .run();
 }
 
-/// Test 9: Non-owner calls setRelayer — fails.
+/// Test 9: Non-owner calls addRelayer — fails.
 #[test]
-fn non_owner_cannot_set_relayer() {
+fn non_owner_cannot_add_relayer() {
     let mut world = world();
 
     world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
@@ -412,14 +525,14 @@ fn non_owner_cannot_set_relayer() {
         .new_address(SC_ADDRESS)
         .run();
 
-    // Non-owner tries to set relayer
+    // Non-owner tries to add a relayer
     world
         .tx()
         .from(NON_OWNER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(dialectical_payments::DialecticalPaymentsProxy)
-        .set_relayer(NON_OWNER_ADDRESS.to_managed_address())
-        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .add_relayer(NON_OWNER_ADDRESS.to_managed_address(), "rogue", 0u64)
+        .with_result(ExpectError(4, "Caller lacks the required role"))
         .run();
 }
 
@@ -481,3 +594,3758 @@ fn update_tier_pricing() {
         .egld(new_price)
         .run();
 }
+
+/// Test 11: Owner withdraws subscription revenue to the payout address.
+#[test]
+fn owner_withdraws_revenue() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(10_000_000_000_000_000u128);
+    world.account(PAYOUT_ADDRESS).nonce(1).balance(0u64);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    let thinker_price = 4_000_000_000_000_000u64;
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, thinker_price)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_payout_address(PAYOUT_ADDRESS.to_managed_address())
+        .run();
+
+    // Withdraw half of the revenue to the default payout address
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(thinker_price / 2, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .run();
+
+    world.check_account(PAYOUT_ADDRESS).balance(thinker_price / 2);
+
+    // Balance = revenue - withdrawn
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_treasury_status()
+        .returns(ExpectValue(MultiValue5::from((
+            BigUint::<StaticApi>::from(thinker_price / 2),
+            BigUint::<StaticApi>::from(thinker_price),
+            BigUint::<StaticApi>::from(thinker_price / 2),
+            BigUint::<StaticApi>::zero(),
+            BigUint::<StaticApi>::zero(),
+        ))))
+        .run();
+
+    // Non-owner cannot withdraw
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(1u64, OptionalValue::Some(USER_ADDRESS.to_managed_address()))
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+/// Test 12: Per-call and per-day withdrawal caps are enforced; the daily cap resets.
+#[test]
+fn withdraw_respects_limits() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(100_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    let scholar_price = 10_000_000_000_000_000u64;
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(2u8, scholar_price)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(2u8)
+        .egld(scholar_price)
+        .run();
+
+    // Max 0.004 EGLD per call, 0.006 EGLD per day
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_withdrawal_limits(4_000_000_000_000_000u64, 6_000_000_000_000_000u64)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(5_000_000_000_000_000u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Amount exceeds per-call withdrawal limit"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(4_000_000_000_000_000u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(4_000_000_000_000_000u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Amount exceeds daily withdrawal limit"))
+        .run();
+
+    // Next day — the daily allowance is available again
+    world.current_block().block_timestamp(24 * 60 * 60);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(4_000_000_000_000_000u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .run();
+}
+
+/// Test 13: Revenue split credits payees pro rata; share changes only affect later payments.
+#[test]
+fn revenue_split_credits_payees() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(100_000_000_000_000_000u128);
+    world.account(PAYEE_A_ADDRESS).nonce(1).balance(0u64);
+    world.account(PAYEE_B_ADDRESS).nonce(1).balance(0u64);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    let thinker_price = 4_000_000_000_000_000u64;
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, thinker_price)
+        .run();
+
+    // A share that would wrap the running total is still over 100%
+    let mut split = MultiValueEncoded::<StaticApi, MultiValue2<ManagedAddress<StaticApi>, u32>>::new();
+    split.push((PAYEE_A_ADDRESS.to_managed_address(), 6_000u32).into());
+    split.push((PAYEE_B_ADDRESS.to_managed_address(), u32::MAX - 5_000).into());
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_revenue_split(split)
+        .with_result(ExpectError(4, "Shares exceed 10000 basis points"))
+        .run();
+
+    // Payee A gets 50%, payee B gets 25%, treasury keeps 25%
+    let mut split = MultiValueEncoded::<StaticApi, MultiValue2<ManagedAddress<StaticApi>, u32>>::new();
+    split.push((PAYEE_A_ADDRESS.to_managed_address(), 5_000u32).into());
+    split.push((PAYEE_B_ADDRESS.to_managed_address(), 2_500u32).into());
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_revenue_split(split)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    // Payee B leaves the split — their earlier credit is kept
+    let mut split = MultiValueEncoded::<StaticApi, MultiValue2<ManagedAddress<StaticApi>, u32>>::new();
+    split.push((PAYEE_A_ADDRESS.to_managed_address(), 5_000u32).into());
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_revenue_split(split)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_claimable_revenue(PAYEE_B_ADDRESS.to_managed_address())
+        .returns(ExpectValue(BigUint::<StaticApi>::from(thinker_price / 4)))
+        .run();
+
+    // Owner can only withdraw what is not owed to payees: 2 * price - price - price / 4
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(thinker_price, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Insufficient withdrawable balance"))
+        .run();
+
+    world
+        .tx()
+        .from(PAYEE_A_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .claim_revenue()
+        .run();
+
+    world
+        .tx()
+        .from(PAYEE_B_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .claim_revenue()
+        .run();
+
+    world.check_account(PAYEE_A_ADDRESS).balance(thinker_price);
+    world.check_account(PAYEE_B_ADDRESS).balance(thinker_price / 4);
+
+    // Balance = revenue - withdrawn - claimed, nothing left reserved
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_treasury_status()
+        .returns(ExpectValue(MultiValue5::from((
+            BigUint::<StaticApi>::from(thinker_price * 3 / 4),
+            BigUint::<StaticApi>::from(thinker_price * 2),
+            BigUint::<StaticApi>::zero(),
+            BigUint::<StaticApi>::from(thinker_price * 5 / 4),
+            BigUint::<StaticApi>::zero(),
+        ))))
+        .run();
+
+    world
+        .tx()
+        .from(PAYEE_B_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .claim_revenue()
+        .with_result(ExpectError(4, "Nothing to claim"))
+        .run();
+}
+
+/// Test 14: Early renewal stacks 30 days onto the remaining time and keeps the current period.
+#[test]
+fn early_renewal_extends_expiry() {
+    let mut world = world();
+    let thinker_price = 4_000_000_000_000_000u64;
+    setup_subscribed_user(&mut world, thinker_price);
+
+    world.current_block().block_timestamp(10 * DAY);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    let sub = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .check_subscription(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sub.expires_at, 60 * DAY);
+    assert_eq!(sub.period_ends_at, 30 * DAY);
+
+    // Once the first period is over, the second one runs until the new expiry
+    world.current_block().block_timestamp(45 * DAY);
+
+    let sub = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .check_subscription(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sub.expires_at, 60 * DAY);
+    assert_eq!(sub.period_ends_at, 60 * DAY);
+}
+
+/// Test 15: Renewal after the subscription lapsed starts a fresh period from now.
+#[test]
+fn renewal_after_lapse_starts_from_now() {
+    let mut world = world();
+    let thinker_price = 4_000_000_000_000_000u64;
+    setup_subscribed_user(&mut world, thinker_price);
+
+    world.current_block().block_timestamp(31 * DAY);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    let sub = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .check_subscription(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sub.expires_at, 61 * DAY);
+    assert_eq!(sub.period_ends_at, 61 * DAY);
+    assert_eq!(sub.arguments_used, 0);
+}
+
+/// Test 16: Renewal exactly at expiry counts as a new period starting at the old expiry.
+#[test]
+fn renewal_on_expiry_boundary() {
+    let mut world = world();
+    let thinker_price = 4_000_000_000_000_000u64;
+    setup_subscribed_user(&mut world, thinker_price);
+
+    world.current_block().block_timestamp(30 * DAY);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    let sub = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .check_subscription(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sub.expires_at, 60 * DAY);
+    assert_eq!(sub.period_ends_at, 60 * DAY);
+    assert_eq!(sub.arguments_used, 0);
+}
+
+/// Test 17: Tier upgrade charges the prorated difference; downgrade converts leftover value to time.
+#[test]
+fn change_tier_prorates() {
+    let mut world = world();
+    let thinker_price = 4_000_000_000_000_000u64;
+    let scholar_price = 10_000_000_000_000_000u64;
+    setup_subscribed_user(&mut world, thinker_price);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(2u8, scholar_price)
+        .run();
+
+    world.current_block().block_timestamp(15 * DAY);
+
+    // subscribe() no longer silently replaces an active subscription of another tier
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(2u8)
+        .egld(scholar_price)
+        .with_result(ExpectError(
+            4,
+            "Active subscription has a different tier, use changeTier",
+        ))
+        .run();
+
+    // Half the period is left: upgrade costs (0.01 - 0.004) / 2 = 0.003 EGLD
+    let upgrade_cost = (scholar_price - thinker_price) / 2;
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .change_tier(2u8)
+        .egld(upgrade_cost - 1)
+        .with_result(ExpectError(4, "Insufficient payment for tier upgrade"))
+        .run();
+
+    // Overpayment is refunded
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .change_tier(2u8)
+        .egld(thinker_price)
+        .run();
+
+    world
+        .check_account(USER_ADDRESS)
+        .balance(100_000_000_000_000_000u64 - thinker_price - upgrade_cost);
+
+    let sub = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .check_subscription(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sub.tier, 2u8);
+    assert_eq!(sub.expires_at, 30 * DAY);
+
+    // Downgrade back: 15 days of scholar value buys 37.5 days of thinker
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .change_tier(1u8)
+        .run();
+
+    let sub = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .check_subscription(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sub.tier, 1u8);
+    assert_eq!(sub.expires_at, 15 * DAY + 75 * DAY / 2);
+}
+
+/// Test 18: consumeQuota enforces the tier quota and resets at the period boundary.
+#[test]
+fn quota_enforced_per_period() {
+    let mut world = world();
+    let thinker_price = 4_000_000_000_000_000u64;
+    setup_subscribed_user(&mut world, thinker_price);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_quota(1u8, 2u64)
+        .run();
+
+    // Renew early so the subscription spans two periods
+    world.current_block().block_timestamp(10 * DAY);
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .consume_quota(USER_ADDRESS.to_managed_address(), 1u64)
+        .with_result(ExpectError(4, "Only relayer can consume quota"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .consume_quota(USER_ADDRESS.to_managed_address(), 2u64)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .consume_quota(USER_ADDRESS.to_managed_address(), 1u64)
+        .with_result(ExpectError(4, "Argument quota exhausted"))
+        .run();
+
+    // New billing period — counter starts over
+    world.current_block().block_timestamp(31 * DAY);
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .consume_quota(USER_ADDRESS.to_managed_address(), 1u64)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .consume_quota(USER_ADDRESS.to_managed_address(), 0u64)
+        .with_result(ExpectError(4, "Count must be greater than zero"))
+        .run();
+
+    // A count that would wrap the counter is rejected, not wrapped back under the quota
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .consume_quota(USER_ADDRESS.to_managed_address(), u64::MAX)
+        .with_result(ExpectError(4, "Argument quota exhausted"))
+        .run();
+
+    let sub = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .check_subscription(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sub.arguments_used, 1u64);
+    assert_eq!(sub.period_ends_at, 60 * DAY);
+}
+
+/// Test 19: Arguments with an author are checked against the author's subscription and indexed.
+#[test]
+fn store_argument_with_author() {
+    let mut world = world();
+    let thinker_price = 4_000_000_000_000_000u64;
+    setup_subscribed_user(&mut world, thinker_price);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_quota(1u8, 1u64)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(
+            1u64,
+            1u64,
+            0u8,
+            8000u32,
+            "Authored argument",
+            no_simhash(),
+            OptionalValue::Some(USER_ADDRESS.to_managed_address()),
+            no_signature(),
+        )
+        .run();
+
+    // Quota of 1 is now used up
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(
+            2u64,
+            1u64,
+            1u8,
+            8000u32,
+            "Second authored argument",
+            no_simhash(),
+            OptionalValue::Some(USER_ADDRESS.to_managed_address()),
+            no_signature(),
+        )
+        .with_result(ExpectError(4, "Argument quota exhausted"))
+        .run();
+
+    // Authors without a subscription are rejected
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(
+            2u64,
+            1u64,
+            1u8,
+            8000u32,
+            "Unsubscribed author",
+            no_simhash(),
+            OptionalValue::Some(NON_OWNER_ADDRESS.to_managed_address()),
+            no_signature(),
+        )
+        .with_result(ExpectError(4, "No active subscription"))
+        .run();
+
+    // Zero-address path is unaffected
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 1u64, 1u8, 8000u32, "Anonymous argument", no_simhash(), no_author(), no_signature())
+        .run();
+
+    let ids = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_author_arguments(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(ids.into_vec(), vec![1u64]);
+}
+
+/// Test 20: Author-signed arguments are verified, marked, and cannot be replayed.
+#[test]
+fn store_author_signed_argument() {
+    let mut world = world();
+    let thinker_price = 4_000_000_000_000_000u64;
+    setup_subscribed_user(&mut world, thinker_price);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    // The author's wallet address is their ed25519 public key
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let author = Address::from(signing_key.verifying_key().to_bytes());
+    world.account(&author).nonce(1).balance(100_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(&author)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 5u64);
+
+    let text = "Signed argument";
+    let signature = signing_key
+        .sign(&author_message(1, 5, 0, None, text, 0))
+        .to_bytes();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(
+            1u64,
+            5u64,
+            0u8,
+            8000u32,
+            text,
+            no_simhash(),
+            OptionalValue::Some(ManagedAddress::<StaticApi>::from(&author)),
+            OptionalValue::Some(MultiValue2::from((
+                0u64,
+                ManagedBuffer::<StaticApi>::from(&signature[..]),
+            ))),
+        )
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_author_nonce(ManagedAddress::<StaticApi>::from(&author))
+        .returns(ExpectValue(1u64))
+        .run();
+
+    // Replaying the same signature for a new id fails on the nonce
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(
+            2u64,
+            5u64,
+            0u8,
+            8000u32,
+            text,
+            no_simhash(),
+            OptionalValue::Some(ManagedAddress::<StaticApi>::from(&author)),
+            OptionalValue::Some(MultiValue2::from((
+                0u64,
+                ManagedBuffer::<StaticApi>::from(&signature[..]),
+            ))),
+        )
+        .with_result(ExpectError(4, "Invalid author nonce"))
+        .run();
+
+    // A signature that does not cover the submitted text is rejected
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(
+            2u64,
+            5u64,
+            0u8,
+            8000u32,
+            "Forged text",
+            no_simhash(),
+            OptionalValue::Some(ManagedAddress::<StaticApi>::from(&author)),
+            OptionalValue::Some(MultiValue2::from((
+                1u64,
+                ManagedBuffer::<StaticApi>::from(&signature[..]),
+            ))),
+        )
+        .with_result(ExpectMessage("ed25519 verify error"))
+        .run();
+
+    // A signature for a top-level argument cannot be reused to attach it as a reply
+    let reply_text = "Signed reply";
+    let signature = signing_key
+        .sign(&author_message(2, 5, 1, None, reply_text, 1))
+        .to_bytes();
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_reply(
+            2u64,
+            5u64,
+            1u8,
+            8000u32,
+            reply_text,
+            no_simhash(),
+            1u64,
+            1u8,
+            OptionalValue::Some(ManagedAddress::<StaticApi>::from(&author)),
+            OptionalValue::Some(MultiValue2::from((
+                1u64,
+                ManagedBuffer::<StaticApi>::from(&signature[..]),
+            ))),
+        )
+        .with_result(ExpectMessage("ed25519 verify error"))
+        .run();
+
+    let signature = signing_key
+        .sign(&author_message(2, 5, 1, Some((1, 1)), reply_text, 1))
+        .to_bytes();
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_reply(
+            2u64,
+            5u64,
+            1u8,
+            8000u32,
+            reply_text,
+            no_simhash(),
+            1u64,
+            1u8,
+            OptionalValue::Some(ManagedAddress::<StaticApi>::from(&author)),
+            OptionalValue::Some(MultiValue2::from((
+                1u64,
+                ManagedBuffer::<StaticApi>::from(&signature[..]),
+            ))),
+        )
+        .run();
+
+    let metadata = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_metadata(2u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(metadata.author_verified);
+}
+
+/// Test 21: Anchored arguments store only a hash and locator; verifyArgument checks the text.
+#[test]
+fn anchor_and_verify_argument() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    let text = "C".repeat(4096);
+    let content_hash: [u8; 32] = Sha256::digest(text.as_bytes()).into();
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .anchor_argument(
+            1u64,
+            1u64,
+            0u8,
+            9000u32,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&content_hash),
+            "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            no_simhash(),
+            no_author(),
+        )
+        .run();
+
+    // Existing full-text path keeps working alongside
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 1u64, 1u8, 7000u32, "Full text", no_simhash(), no_author(), no_signature())
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .anchor_argument(
+            2u64,
+            1u64,
+            0u8,
+            9000u32,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&content_hash),
+            "db:2",
+            no_simhash(),
+            no_author(),
+        )
+        .with_result(ExpectError(4, "Argument ID already exists"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .verify_argument(1u64, text.as_str())
+        .returns(ExpectValue(true))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .verify_argument(1u64, "tampered")
+        .returns(ExpectValue(false))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .verify_argument(2u64, "Full text")
+        .returns(ExpectValue(true))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument(1u64)
+        .with_result(ExpectError(4, "Argument text is stored off-chain"))
+        .run();
+}
+
+/// Test 22: Chunked upload — text is only visible after finalize checks length and hash.
+#[test]
+fn chunked_argument_upload() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    let first = "D".repeat(3000);
+    let second = "E".repeat(2000);
+    let full = format!("{first}{second}");
+    let content_hash: [u8; 32] = Sha256::digest(full.as_bytes()).into();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .begin_argument(
+            1u64,
+            1u64,
+            2u8,
+            9500u32,
+            full.len() as u32,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&content_hash),
+            no_simhash(),
+            no_author(),
+        )
+        .run();
+
+    for chunk in [&first, &second] {
+        world
+            .tx()
+            .from(RELAYER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .append_argument_chunk(1u64, chunk.as_str())
+            .run();
+    }
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .append_argument_chunk(1u64, "F")
+        .with_result(ExpectError(4, "Chunk exceeds declared length"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument(1u64)
+        .with_result(ExpectError(4, "Argument not found"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .finalize_argument(1u64)
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .verify_argument(1u64, full.as_str())
+        .returns(ExpectValue(true))
+        .run();
+
+    // A declared hash that does not match the uploaded text is rejected
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .begin_argument(
+            2u64,
+            1u64,
+            1u8,
+            9500u32,
+            3u32,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&content_hash),
+            no_simhash(),
+            no_author(),
+        )
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .append_argument_chunk(2u64, "abc")
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .finalize_argument(2u64)
+        .with_result(ExpectError(4, "Text hash does not match declared hash"))
+        .run();
+}
+
+/// Test 23: Replies form a tree — parent must be in the same debate and depth is capped.
+#[test]
+fn debate_tree_links() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+    create_debate(&mut world, 2u64);
+
+    // Thesis (id 1) in debate 1, then a chain of replies 2..=11 reaching depth 10
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 2u8, 9000u32, "Thesis", no_simhash(), no_author(), no_signature())
+        .run();
+
+    for id in 2u64..=11 {
+        world
+            .tx()
+            .from(RELAYER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .store_reply(
+                id,
+                1u64,
+                (id % 2) as u8,
+                8000u32,
+                format!("Reply {}", id).as_str(),
+                no_simhash(),
+                id - 1,
+                1u8,
+                no_author(),
+                no_signature(),
+            )
+            .run();
+    }
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_reply(12u64, 1u64, 0u8, 8000u32, "Too deep", no_simhash(), 11u64, 0u8, no_author(), no_signature())
+        .with_result(ExpectError(4, "Maximum tree depth exceeded"))
+        .run();
+
+    // Parent must be in the same debate
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_reply(12u64, 2u64, 0u8, 8000u32, "Other debate", no_simhash(), 1u64, 0u8, no_author(), no_signature())
+        .with_result(ExpectError(4, "Parent argument belongs to another debate"))
+        .run();
+
+    // Second reply to the thesis
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_reply(12u64, 1u64, 0u8, 8000u32, "Support", no_simhash(), 1u64, 0u8, no_author(), no_signature())
+        .run();
+
+    let children = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_children(1u64)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(children.into_vec(), vec![2u64, 12u64]);
+
+    let path = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_path(4u64)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(path.into_vec(), vec![4u64, 3u64, 2u64, 1u64]);
+}
+
+/// Test 24: Debates must be registered; the first THESIS argument is recorded on the debate.
+#[test]
+fn debate_registry() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 7u64, 2u8, 9000u32, "Thesis", no_simhash(), no_author(), no_signature())
+        .with_result(ExpectError(4, "Debate not found"))
+        .run();
+
+    world.current_block().block_timestamp(1_000u64);
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .create_debate(
+            7u64,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[7u8; 32]),
+            OptionalValue::Some(USER_ADDRESS.to_managed_address()),
+        )
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .create_debate(
+            7u64,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[7u8; 32]),
+            no_author(),
+        )
+        .with_result(ExpectError(4, "Debate already exists"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 7u64, 2u8, 9000u32, "Thesis", no_simhash(), no_author(), no_signature())
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 7u64, 2u8, 9000u32, "Second thesis", no_simhash(), no_author(), no_signature())
+        .with_result(ExpectError(4, "Debate already has a thesis"))
+        .run();
+
+    let debate = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate(7u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(debate.creator, USER_ADDRESS.to_managed_address());
+    assert_eq!(debate.thesis_id, Some(1u64));
+    assert_eq!(debate.created_at, 1_000u64);
+}
+
+/// Test 25: Closing a debate records the verdict and freezes it; closed debates can be archived.
+#[test]
+fn close_debate_records_verdict() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+    create_debate(&mut world, 2u64);
+
+    for (id, argument_type) in [(1u64, 2u8), (2u64, 0u8), (3u64, 1u8)] {
+        world
+            .tx()
+            .from(RELAYER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .store_argument(
+                id,
+                1u64,
+                argument_type,
+                8000u32,
+                format!("Argument {}", id).as_str(),
+                no_simhash(),
+                no_author(),
+                no_signature(),
+            )
+            .run();
+    }
+
+    let verdict = |decisive: &[u64]| DebateVerdict {
+        winning_side: 0u8,
+        decisive_argument_ids: ManagedVec::from_iter(decisive.iter().copied()),
+        pro_votes: 12u64,
+        con_votes: 5u64,
+        final_score: 7_100u32,
+    };
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .close_debate(1u64, verdict(&[2u64]))
+        .with_result(ExpectError(4, "Only relayer or admin can close debates"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .close_debate(2u64, verdict(&[2u64]))
+        .with_result(ExpectError(4, "Decisive argument not in debate"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .close_debate(1u64, verdict(&[2u64]))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(4u64, 1u64, 0u8, 8000u32, "Too late", no_simhash(), no_author(), no_signature())
+        .with_result(ExpectError(4, "Debate is not open"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .close_debate(1u64, verdict(&[]))
+        .with_result(ExpectError(4, "Debate is not open"))
+        .run();
+
+    let debate = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate(1u64)
+        .returns(ReturnsResult)
+        .run();
+    let recorded = debate.verdict.expect("verdict recorded");
+    assert_eq!(recorded.winning_side, 0u8);
+    assert_eq!(recorded.decisive_argument_ids.len(), 1);
+    assert_eq!(recorded.pro_votes, 12u64);
+    assert_eq!(recorded.final_score, 7_100u32);
+
+    // Only closed debates can be archived
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .archive_debate(2u64)
+        .with_result(ExpectError(4, "Debate is not closed"))
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .archive_debate(1u64)
+        .with_result(ExpectError(4, "Only relayer or admin can archive debates"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .archive_debate(1u64)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .archive_debate(1u64)
+        .with_result(ExpectError(4, "Debate is not closed"))
+        .run();
+
+    let debate = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(matches!(debate.state, DebateState::Archived));
+    assert!(debate.verdict.is_some());
+}
+
+/// Test 26: Revisions keep every earlier version; getArgument returns the latest.
+#[test]
+fn revise_argument_keeps_history() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 0u8, 6000u32, "Draft", no_simhash(), no_author(), no_signature())
+        .run();
+
+    world.current_block().block_timestamp(100u64);
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .revise_argument(1u64, "Refined", 8000u32)
+        .run();
+
+    world.current_block().block_timestamp(200u64);
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .revise_argument(1u64, "Final", 9000u32)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .revise_argument(2u64, "Missing", 9000u32)
+        .with_result(ExpectError(4, "Argument not found"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument(1u64)
+        .returns(ExpectValue(ManagedBuffer::<StaticApi>::from("Final")))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_version_count(1u64)
+        .returns(ExpectValue(3usize))
+        .run();
+
+    let original = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_version(1u64, 1usize)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(original.text, ManagedBuffer::<StaticApi>::from("Draft"));
+    assert_eq!(original.quality_score, 6000u32);
+    assert_eq!(original.timestamp, 0u64);
+
+    let second = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_version(1u64, 2usize)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(second.text, ManagedBuffer::<StaticApi>::from("Refined"));
+    assert_eq!(second.timestamp, 100u64);
+
+    let latest = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_version(1u64, 3usize)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(latest.quality_score, 9000u32);
+    assert_eq!(latest.timestamp, 200u64);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_version(1u64, 4usize)
+        .with_result(ExpectError(4, "Version not found"))
+        .run();
+}
+
+/// Test 27: Moderators redact arguments — text is erased, the original hash is kept.
+#[test]
+fn moderator_redacts_argument() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+    world.account(MODERATOR_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 0u8, 5000u32, "Abusive draft", no_simhash(), no_author(), no_signature())
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .revise_argument(1u64, "Abusive text", 5000u32)
+        .run();
+
+    world
+        .tx()
+        .from(MODERATOR_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .redact_argument(1u64, 3u8)
+        .with_result(ExpectError(4, "Caller lacks the required role"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .grant_role(MODERATOR_ADDRESS.to_managed_address(), ROLE_MODERATOR)
+        .run();
+
+    world
+        .tx()
+        .from(MODERATOR_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .redact_argument(1u64, 3u8)
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument(1u64)
+        .with_result(ExpectError(4, "Argument has been redacted"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_version(1u64, 1usize)
+        .with_result(ExpectError(4, "Argument has been redacted"))
+        .run();
+
+    // Provenance: the original text still verifies against the kept hash
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .verify_argument(1u64, "Abusive text")
+        .returns(ExpectValue(true))
+        .run();
+
+    let redaction = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_redaction(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(redaction.reason_code, 3u8);
+    assert_eq!(redaction.moderator, MODERATOR_ADDRESS.to_managed_address());
+    // The blanked earlier version stays provable through its kept hash
+    let draft_hash: [u8; 32] = Sha256::digest(b"Abusive draft").into();
+    assert_eq!(redaction.version_hashes.len(), 1);
+    assert_eq!(redaction.version_hashes.get(0).to_byte_array(), draft_hash);
+
+    world
+        .tx()
+        .from(MODERATOR_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .redact_argument(1u64, 3u8)
+        .with_result(ExpectError(4, "Argument already redacted"))
+        .run();
+}
+
+/// Test 28: Batch store — all items stored in one call, or none on any failure.
+#[test]
+fn store_arguments_batch() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    let mut batch = MultiValueEncoded::<StaticApi, MultiValue6<u64, u64, u8, u32, ManagedBuffer<StaticApi>, Option<u64>>>::new();
+    batch.push((1u64, 1u64, 2u8, 9000u32, ManagedBuffer::from("Thesis"), no_simhash()).into());
+    batch.push((2u64, 1u64, 0u8, 7000u32, ManagedBuffer::from("Antithesis"), no_simhash()).into());
+    batch.push((3u64, 1u64, 1u8, 8000u32, ManagedBuffer::from("Synthesis"), no_simhash()).into());
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_arguments(batch)
+        .run();
+
+    let ids = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments(1u64)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(ids.into_vec(), vec![1u64, 2u64, 3u64]);
+
+    // Item 5 reuses ID 3 — the whole batch is rejected, item 4 included
+    let mut batch = MultiValueEncoded::<StaticApi, MultiValue6<u64, u64, u8, u32, ManagedBuffer<StaticApi>, Option<u64>>>::new();
+    batch.push((4u64, 1u64, 0u8, 6000u32, ManagedBuffer::from("Objection"), no_simhash()).into());
+    batch.push((3u64, 1u64, 0u8, 6000u32, ManagedBuffer::from("Duplicate"), no_simhash()).into());
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_arguments(batch)
+        .with_result(ExpectError(4, "Argument ID already exists"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument(4u64)
+        .with_result(ExpectError(4, "Argument not found"))
+        .run();
+
+    let mut batch = MultiValueEncoded::<StaticApi, MultiValue6<u64, u64, u8, u32, ManagedBuffer<StaticApi>, Option<u64>>>::new();
+    for id in 10u64..61 {
+        batch.push((id, 1u64, 0u8, 5000u32, ManagedBuffer::from("Filler"), no_simhash()).into());
+    }
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_arguments(batch)
+        .with_result(ExpectError(4, "Batch exceeds maximum size"))
+        .run();
+}
+
+/// Test 29: Paginated and filtered debate argument views.
+#[test]
+fn debate_arguments_paginated_and_filtered() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    let mut batch = MultiValueEncoded::<StaticApi, MultiValue6<u64, u64, u8, u32, ManagedBuffer<StaticApi>, Option<u64>>>::new();
+    batch.push((1u64, 1u64, 2u8, 9000u32, ManagedBuffer::from("Thesis"), no_simhash()).into());
+    batch.push((2u64, 1u64, 0u8, 4000u32, ManagedBuffer::from("Weak pro"), no_simhash()).into());
+    batch.push((3u64, 1u64, 1u8, 8000u32, ManagedBuffer::from("Strong con"), no_simhash()).into());
+    batch.push((4u64, 1u64, 0u8, 7000u32, ManagedBuffer::from("Strong pro"), no_simhash()).into());
+    batch.push((5u64, 1u64, 0u8, 9500u32, ManagedBuffer::from("Best pro"), no_simhash()).into());
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_arguments(batch)
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_argument_count(1u64)
+        .returns(ExpectValue(5usize))
+        .run();
+
+    let page = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments_page(1u64, 1usize, 2usize)
+        .returns(ReturnsResult)
+        .run();
+    let page: Vec<_> = page.into_iter().collect();
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].id, 2u64);
+    assert_eq!(page[0].text_length, 8u32);
+    assert_eq!(page[1].id, 3u64);
+    assert_eq!(page[1].metadata.quality_score, 8000u32);
+
+    // PRO arguments scoring at least 5000: IDs 4 and 5 (positions 2 and 3 of the PRO index)
+    let (total, next) = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_argument_count_filtered(1u64, Some(0u8), 5000u32, 0usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!((total, next), (2usize, 3usize));
+
+    // Pages resume from the returned cursor instead of rescanning from the start
+    let (next, page) = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments_filtered(1u64, Some(0u8), 5000u32, 0usize, 1usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    let ids: Vec<u64> = page.into_iter().map(|record| record.id).collect();
+    assert_eq!((next, ids), (2usize, vec![4u64]));
+
+    let (next, page) = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments_filtered(1u64, Some(0u8), 5000u32, next, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    let ids: Vec<u64> = page.into_iter().map(|record| record.id).collect();
+    assert_eq!((next, ids), (3usize, vec![5u64]));
+
+    let (total, next) = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_argument_count_filtered(1u64, None::<u8>, 8000u32, 0usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!((total, next), (3usize, 5usize));
+
+    // Type-only filter: all PRO arguments, paged through the per-type index
+    let (total, next) = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_argument_count_filtered(1u64, Some(0u8), 0u32, 0usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!((total, next), (3usize, 3usize));
+
+    let (next, page) = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments_filtered(1u64, Some(0u8), 0u32, 1usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    let ids: Vec<u64> = page.into_iter().map(|record| record.id).collect();
+    assert_eq!((next, ids), (3usize, vec![4u64, 5u64]));
+
+    // Out-of-range offsets return an empty page instead of overflowing
+    let page = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments_page(1u64, u32::MAX as usize, 5usize)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(page.len(), 0);
+
+    let page = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments_page(1u64, 10usize, 5usize)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(page.len(), 0);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments_page(1u64, 0usize, 101usize)
+        .with_result(ExpectError(4, "Page size exceeds maximum"))
+        .run();
+}
+
+/// Test 30: Metadata and combined views — unknown IDs come back as None in multi-get.
+#[test]
+fn argument_metadata_and_full_views() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 1u8, 6500u32, "Counterpoint", no_simhash(), no_author(), no_signature())
+        .run();
+
+    let metadata = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_metadata(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(metadata.debate_id, 1u64);
+    assert_eq!(metadata.argument_type, 1u8);
+    assert_eq!(metadata.quality_score, 6500u32);
+    assert_eq!(metadata.depth, 0u32);
+
+    let full = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_full(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(full.id, 1u64);
+    assert_eq!(full.text, ManagedBuffer::from("Counterpoint"));
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_metadata(2u64)
+        .with_result(ExpectError(4, "Argument not found"))
+        .run();
+
+    let mut ids = MultiValueEncoded::<StaticApi, u64>::new();
+    ids.push(2u64);
+    ids.push(1u64);
+    let results = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_arguments_full(ids)
+        .returns(ReturnsResult)
+        .run();
+    let results: Vec<_> = results.into_iter().collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_none());
+    let found = results[1].as_ref().unwrap();
+    assert_eq!(found.id, 1u64);
+    assert_eq!(found.metadata.quality_score, 6500u32);
+    assert!(matches!(found.status, ArgumentStatus::Stored));
+    assert_eq!(found.text, ManagedBuffer::from("Counterpoint"));
+
+    // Anchored and redacted arguments both have no text; the status tells them apart
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .anchor_argument(
+            3u64,
+            1u64,
+            0u8,
+            7000u32,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[1u8; 32]),
+            "ipfs://anchored",
+            no_simhash(),
+            no_author(),
+        )
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .redact_argument(1u64, 1u8)
+        .run();
+
+    let mut ids = MultiValueEncoded::<StaticApi, u64>::new();
+    ids.push(1u64);
+    ids.push(3u64);
+    let results = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_arguments_full(ids)
+        .returns(ReturnsResult)
+        .run();
+    let results: Vec<_> = results.into_iter().map(Option::unwrap).collect();
+    assert!(matches!(results[0].status, ArgumentStatus::Redacted));
+    assert!(results[0].text.is_empty());
+    assert!(matches!(results[1].status, ArgumentStatus::Anchored));
+    assert!(results[1].text.is_empty());
+
+    let page = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_arguments_page(1u64, 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    let statuses: Vec<_> = page.into_iter().map(|record| record.status).collect();
+    assert!(matches!(
+        statuses[..],
+        [ArgumentStatus::Redacted, ArgumentStatus::Anchored]
+    ));
+}
+
+/// Test 31: Contract-assigned IDs — skip taken IDs, retries return the same ID.
+#[test]
+fn store_argument_auto_assigns_ids() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    // ID 1 is taken by an app-assigned argument
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 0u8, 5000u32, "Manual", no_simhash(), no_author(), no_signature())
+        .run();
+
+    let id = world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument_auto(100u64, 1u64, 1u8, 6000u32, "Auto", no_simhash(), no_author())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(id, 2u64);
+
+    // Retry of the same external ID stores nothing new
+    let retry_id = world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument_auto(100u64, 1u64, 1u8, 6000u32, "Auto", no_simhash(), no_author())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(retry_id, 2u64);
+
+    // Reusing the external ID for another payload is an error, not a silent no-op
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument_auto(100u64, 1u64, 1u8, 6000u32, "Other text", no_simhash(), no_author())
+        .with_result(ExpectError(4, "External ID already used for a different argument"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_debate_argument_count(1u64)
+        .returns(ExpectValue(2usize))
+        .run();
+
+    let id = world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument_auto(101u64, 1u64, 0u8, 7000u32, "Auto again", no_simhash(), no_author())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(id, 3u64);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_id_by_external_id(100u64)
+        .returns(ExpectValue(2u64))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument(3u64)
+        .returns(ExpectValue(ManagedBuffer::from("Auto again")))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_id_by_external_id(999u64)
+        .with_result(ExpectError(4, "External ID not found"))
+        .run();
+}
+
+/// Test 32: Duplicate content — marked by default, rejected when the policy says so.
+#[test]
+fn duplicate_content_marked_or_rejected() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+    create_debate(&mut world, 2u64);
+
+    let text = "Markets allocate scarce resources efficiently.";
+    let content_hash: [u8; 32] = Sha256::digest(text.as_bytes()).into();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 0u8, 8000u32, text, no_simhash(), no_author(), no_signature())
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 1u64, 0u8, 8000u32, text, no_simhash(), no_author(), no_signature())
+        .run();
+
+    let metadata = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_metadata(2u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(metadata.duplicate_of, Some(1u64));
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .find_argument_by_hash(ManagedByteArray::<StaticApi, 32>::new_from_bytes(&content_hash))
+        .returns(ExpectValue(1u64))
+        .run();
+
+    // Debate 2 rejects duplicates, debate 1 keeps the global default
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_debate_duplicate_policy(2u64, OptionalValue::Some(DuplicatePolicy::Reject))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(3u64, 2u64, 0u8, 8000u32, text, no_simhash(), no_author(), no_signature())
+        .with_result(ExpectError(4, "Duplicate argument content"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(3u64, 2u64, 0u8, 8000u32, "Fresh text", no_simhash(), no_author(), no_signature())
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_duplicate_policy(1u64)
+        .returns(ExpectValue(DuplicatePolicy::Mark))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_duplicate_policy(DuplicatePolicy::Reject)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(4u64, 1u64, 0u8, 8000u32, text, no_simhash(), no_author(), no_signature())
+        .with_result(ExpectError(4, "Duplicate argument content"))
+        .run();
+
+    // Revisions are checked and indexed like new arguments
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .revise_argument(3u64, text, 8000u32)
+        .with_result(ExpectError(4, "Duplicate argument content"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .revise_argument(3u64, "Fresh text, revised", 8000u32)
+        .run();
+
+    let revised_hash: [u8; 32] = Sha256::digest(b"Fresh text, revised").into();
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .find_argument_by_hash(ManagedByteArray::<StaticApi, 32>::new_from_bytes(&revised_hash))
+        .returns(ExpectValue(3u64))
+        .run();
+}
+
+/// Test 33: SimHash near-duplicates — flagged within the threshold, rejected under Reject.
+#[test]
+fn simhash_near_duplicates() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    // The scan is always bounded
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_simhash_settings(4u32, 0u32)
+        .with_result(ExpectError(4, "Scan limit must be 1-1000"))
+        .run();
+
+    // Distances below 4 bits are near-duplicates, compare against the 10 most recent fingerprints
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_simhash_settings(4u32, 10u32)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    let base: u64 = 0xF0F0_F0F0_0000_FFFF;
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 0u8, 8000u32, "Taxes fund roads.", Some(base), no_author(), no_signature())
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 1u64, 1u8, 7000u32, "Roads pay for themselves.", Some(!base), no_author(), no_signature())
+        .run();
+
+    // Two bits away from argument 1
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(3u64, 1u64, 0u8, 8000u32, "Roads are funded by taxes.", Some(base ^ 0b101), no_author(), no_signature())
+        .run();
+
+    let metadata = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_metadata(3u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(metadata.near_duplicate_of, Some(1u64));
+
+    let metadata = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_metadata(2u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(metadata.near_duplicate_of, None);
+
+    // Every other store path checks and records the fingerprint too
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_reply(10u64, 1u64, 0u8, 8000u32, "Roads need taxes.", Some(base ^ 0b1), 1u64, 0u8, no_author(), no_signature())
+        .run();
+
+    let auto_id = world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument_auto(100u64, 1u64, 0u8, 8000u32, "Taxes build roads.", Some(base ^ 0b10), no_author())
+        .returns(ReturnsResult)
+        .run();
+
+    let anchored_hash: [u8; 32] = Sha256::digest(b"Taxes pave roads.").into();
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .anchor_argument(
+            11u64,
+            1u64,
+            0u8,
+            8000u32,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&anchored_hash),
+            "ipfs://roads",
+            Some(base ^ 0b100),
+            no_author(),
+        )
+        .run();
+
+    let mut batch = MultiValueEncoded::<StaticApi, MultiValue6<u64, u64, u8, u32, ManagedBuffer<StaticApi>, Option<u64>>>::new();
+    batch.push((12u64, 1u64, 0u8, 8000u32, ManagedBuffer::from("Road taxes work."), Some(base ^ 0b1000)).into());
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_arguments(batch)
+        .run();
+
+    let chunked = "Taxes keep roads open.";
+    let chunked_hash: [u8; 32] = Sha256::digest(chunked.as_bytes()).into();
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .begin_argument(
+            13u64,
+            1u64,
+            0u8,
+            8000u32,
+            chunked.len() as u32,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&chunked_hash),
+            Some(base ^ 0b10000),
+            no_author(),
+        )
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .append_argument_chunk(13u64, chunked)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .finalize_argument(13u64)
+        .run();
+
+    for id in [10u64, auto_id, 11u64, 12u64, 13u64] {
+        let metadata = world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .get_argument_metadata(id)
+            .returns(ReturnsResult)
+            .run();
+        assert!(metadata.near_duplicate_of.is_some());
+    }
+
+    let (match_id, distance) = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_nearest_fingerprint_match(1u64, !base ^ 1)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!(match_id, 2u64);
+    assert_eq!(distance, 1u32);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_debate_duplicate_policy(1u64, OptionalValue::Some(DuplicatePolicy::Reject))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(20u64, 1u64, 1u8, 7000u32, "Roads never pay.", Some(!base ^ 0b11), no_author(), no_signature())
+        .with_result(ExpectError(4, "Argument is a near-duplicate"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_nearest_fingerprint_match(2u64, base)
+        .with_result(ExpectError(4, "No fingerprints in debate"))
+        .run();
+}
+
+/// Test 34: Relayer whitelist — several relayers, per-relayer daily caps, removal.
+#[test]
+fn relayer_whitelist_with_daily_caps() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+    world.account(RELAYER_B_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "eu-west", 0u64)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_B_ADDRESS.to_managed_address(), "us-east", 1u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    world
+        .tx()
+        .from(RELAYER_B_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 0u8, 5000u32, "From the US", no_simhash(), no_author(), no_signature())
+        .run();
+
+    let metadata = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument_metadata(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(metadata.relayer, RELAYER_B_ADDRESS.to_managed_address());
+
+    world
+        .tx()
+        .from(RELAYER_B_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 1u64, 0u8, 5000u32, "Over the cap", no_simhash(), no_author(), no_signature())
+        .with_result(ExpectError(4, "Relayer daily cap reached"))
+        .run();
+
+    // The cap resets the next day
+    world.current_block().block_timestamp(DAY);
+    world
+        .tx()
+        .from(RELAYER_B_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 1u64, 0u8, 5000u32, "Next day", no_simhash(), no_author(), no_signature())
+        .run();
+
+    let relayers = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_relayers()
+        .returns(ReturnsResult)
+        .run();
+    let relayers: Vec<_> = relayers.into_iter().map(|entry| entry.into_tuple()).collect();
+    assert_eq!(relayers.len(), 2);
+    let (address, info) = &relayers[1];
+    assert_eq!(*address, RELAYER_B_ADDRESS.to_managed_address());
+    assert_eq!(info.label, ManagedBuffer::from("us-east"));
+    assert_eq!(info.daily_cap, 1u64);
+    assert_eq!(info.stored_today, 1u64);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .remove_relayer(RELAYER_B_ADDRESS.to_managed_address())
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_B_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(3u64, 1u64, 0u8, 5000u32, "Removed key", no_simhash(), no_author(), no_signature())
+        .with_result(ExpectError(4, "Only relayer can store arguments"))
+        .run();
+
+    // The other relayer is unaffected
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(3u64, 1u64, 0u8, 5000u32, "From the EU", no_simhash(), no_author(), no_signature())
+        .run();
+}
+
+/// Test 35: Roles — admins grant narrow roles, each admin endpoint checks its role.
+#[test]
+fn roles_gate_admin_endpoints() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(NON_OWNER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .grant_role(NON_OWNER_ADDRESS.to_managed_address(), ROLE_ADMIN)
+        .run();
+
+    // The new admin hands out pricing rights
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .grant_role(USER_ADDRESS.to_managed_address(), ROLE_PRICING_MANAGER)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, 5_000u64)
+        .run();
+
+    // Admin does not imply pricing manager
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, 1u64)
+        .with_result(ExpectError(4, "Caller lacks the required role"))
+        .run();
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .grant_role(USER_ADDRESS.to_managed_address(), ROLE_RELAYER)
+        .with_result(ExpectError(4, "Use addRelayer for the relayer role"))
+        .run();
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .grant_role(USER_ADDRESS.to_managed_address(), ROLE_ADMIN | ROLE_MODERATOR)
+        .with_result(ExpectError(4, "Invalid role"))
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .grant_role(USER_ADDRESS.to_managed_address(), ROLE_ADMIN)
+        .with_result(ExpectError(4, "Caller lacks the required role"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_roles(USER_ADDRESS.to_managed_address())
+        .returns(ExpectValue(ROLE_PRICING_MANAGER))
+        .run();
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .revoke_role(USER_ADDRESS.to_managed_address(), ROLE_PRICING_MANAGER)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, 1u64)
+        .with_result(ExpectError(4, "Caller lacks the required role"))
+        .run();
+
+    let admins = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_role_members(ROLE_ADMIN)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(admins.into_vec(), vec![NON_OWNER_ADDRESS.to_address()]);
+}
+
+/// Test 36: Pausing subscriptions blocks subscribe, changeTier and cancel; views stay up.
+#[test]
+fn pause_subscriptions() {
+    let mut world = world();
+    let thinker_price: u64 = 10_000_000_000_000_000; // 0.01 EGLD
+
+    setup_subscribed_user(&mut world, thinker_price);
+    world.account(NON_OWNER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .pause(PAUSE_SUBSCRIPTIONS)
+        .with_result(ExpectError(4, "Caller lacks the required role"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .grant_role(NON_OWNER_ADDRESS.to_managed_address(), ROLE_PAUSER)
+        .run();
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .pause(PAUSE_SUBSCRIPTIONS)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .with_result(ExpectError(4, "Subscriptions are paused"))
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .change_tier(1u8)
+        .with_result(ExpectError(4, "Subscriptions are paused"))
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .cancel_subscription()
+        .with_result(ExpectError(4, "Subscriptions are paused"))
+        .run();
+
+    let sub = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .check_subscription(USER_ADDRESS.to_managed_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sub.tier, 1u8);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_paused_groups()
+        .returns(ExpectValue(PAUSE_SUBSCRIPTIONS))
+        .run();
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .unpause(PAUSE_SUBSCRIPTIONS)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+}
+
+/// Test 37: Pausing argument storage blocks every store path but not reads.
+#[test]
+fn pause_argument_storage() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .add_relayer(RELAYER_ADDRESS.to_managed_address(), "primary", 0u64)
+        .run();
+
+    create_debate(&mut world, 1u64);
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(1u64, 1u64, 0u8, 5000u32, "Before the incident", no_simhash(), no_author(), no_signature())
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .pause(PAUSE_ARGUMENTS)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 1u64, 0u8, 5000u32, "During the incident", no_simhash(), no_author(), no_signature())
+        .with_result(ExpectError(4, "Argument storage is paused"))
+        .run();
+
+    let mut batch = MultiValueEncoded::<StaticApi, MultiValue6<u64, u64, u8, u32, ManagedBuffer<StaticApi>, Option<u64>>>::new();
+    batch.push((2u64, 1u64, 0u8, 5000u32, ManagedBuffer::from("Batched"), no_simhash()).into());
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_arguments(batch)
+        .with_result(ExpectError(4, "Argument storage is paused"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .revise_argument(1u64, "Revised", 6000u32)
+        .with_result(ExpectError(4, "Argument storage is paused"))
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .create_debate(
+            2u64,
+            ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[2u8; 32]),
+            no_author(),
+        )
+        .with_result(ExpectError(4, "Argument storage is paused"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_argument(1u64)
+        .returns(ExpectValue(ManagedBuffer::from("Before the incident")))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .unpause(PAUSE_ARGUMENTS)
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .store_argument(2u64, 1u64, 0u8, 5000u32, "After the incident", no_simhash(), no_author(), no_signature())
+        .run();
+}
+
+/// Test 38: Pausing the treasury blocks withdraw and claimRevenue only.
+#[test]
+fn pause_treasury() {
+    let mut world = world();
+    let thinker_price: u64 = 10_000_000_000_000_000; // 0.01 EGLD
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(100_000_000_000_000_000u128);
+    world.account(PAYEE_A_ADDRESS).nonce(1).balance(0u64);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, thinker_price)
+        .run();
+
+    let mut split = MultiValueEncoded::<StaticApi, MultiValue2<ManagedAddress<StaticApi>, u32>>::new();
+    split.push((PAYEE_A_ADDRESS.to_managed_address(), 5_000u32).into());
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_revenue_split(split)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .pause(PAUSE_TREASURY)
+        .run();
+
+    // Subscriptions are a separate group and keep working
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(1u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Treasury operations are paused"))
+        .run();
+
+    world
+        .tx()
+        .from(PAYEE_A_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .claim_revenue()
+        .with_result(ExpectError(4, "Treasury operations are paused"))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_withdrawable_balance()
+        .returns(ExpectValue(BigUint::<StaticApi>::from(thinker_price / 2)))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .unpause(PAUSE_TREASURY)
+        .run();
+
+    world
+        .tx()
+        .from(PAYEE_A_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .claim_revenue()
+        .run();
+
+    world.check_account(PAYEE_A_ADDRESS).balance(thinker_price / 2);
+}
+
+/// Test 39: Timelock — sensitive changes are queued, cancellable, executable after the delay.
+#[test]
+fn timelocked_admin_actions() {
+    let mut world = world();
+    let thinker_price: u64 = 10_000_000_000_000_000; // 0.01 EGLD
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(100_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    // A delay large enough to wrap the ETA or lock admin changes for good is refused
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_timelock_delay(u64::MAX)
+        .with_result(ExpectError(4, "Timelock delay exceeds maximum"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_timelock_delay(MAX_TIMELOCK_DELAY + 1)
+        .with_result(ExpectError(4, "Timelock delay exceeds maximum"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_timelock_delay(DAY)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, thinker_price)
+        .with_result(ExpectError(4, "Action is timelocked, use queueAction"))
+        .run();
+
+    let pricing_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .queue_action(AdminAction::SetTierPricing(1u8, BigUint::from(thinker_price)))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(pricing_id, 1u64);
+
+    let relayer_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .queue_action(AdminAction::AddRelayer(
+            RELAYER_ADDRESS.to_managed_address(),
+            ManagedBuffer::from("suspicious"),
+            0u64,
+        ))
+        .returns(ReturnsResult)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .execute_queued(pricing_id)
+        .with_result(ExpectError(4, "Timelock delay has not passed"))
+        .run();
+
+    // The relayer change is spotted and cancelled during the delay
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .cancel_queued(relayer_id)
+        .run();
+
+    let queued = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_queued_action_ids()
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(queued.into_vec(), vec![pricing_id]);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_timelock_delay(0u64)
+        .with_result(ExpectError(4, "Lowering the delay must be queued"))
+        .run();
+
+    world.current_block().block_timestamp(DAY);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .execute_queued(relayer_id)
+        .with_result(ExpectError(4, "Action not queued"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .execute_queued(pricing_id)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .subscribe(1u8)
+        .egld(thinker_price)
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_relayers()
+        .returns(ExpectValue(MultiValueEncoded::new()))
+        .run();
+}
+
+/// Test 40: Two-step ownership — the proposed owner must accept before taking over.
+#[test]
+fn two_step_ownership_transfer() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(NON_OWNER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+    world.account(USER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .propose_owner(NON_OWNER_ADDRESS.to_managed_address())
+        .run();
+
+    // Nothing changes until the proposal is accepted
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .owner()
+        .returns(ExpectValue(OWNER_ADDRESS.to_managed_address()))
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .accept_ownership()
+        .with_result(ExpectError(4, "Caller is not the pending owner"))
+        .run();
+
+    // The deployer still holds upgrade rights, so the handover cannot complete yet
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .accept_ownership()
+        .with_result(ExpectError(4, "Protocol ownership must be handed to the contract first"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(UserBuiltinProxy)
+        .change_owner_address(&SC_ADDRESS.to_managed_address())
+        .run();
+
+    // Parking protocol ownership in the contract does not change the owner
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .owner()
+        .returns(ExpectValue(OWNER_ADDRESS.to_managed_address()))
+        .run();
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .accept_ownership()
+        .run();
+
+    // Upgrade rights moved with the handover
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_protocol_owner()
+        .returns(ExpectValue(NON_OWNER_ADDRESS.to_managed_address()))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .owner()
+        .returns(ExpectValue(NON_OWNER_ADDRESS.to_managed_address()))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_pending_owner()
+        .returns(ExpectValue(ManagedAddress::<StaticApi>::zero()))
+        .run();
+
+    // The previous owner lost owner rights and implicit roles
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, 1u64)
+        .with_result(ExpectError(4, "Caller lacks the required role"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .propose_owner(OWNER_ADDRESS.to_managed_address())
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_tier_pricing(1u8, 1u64)
+        .run();
+}
+
+/// Test 41: Tier price history — every change is kept, past prices can be looked up.
+#[test]
+fn tier_price_history() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    for (timestamp, price) in [(10u64, 100u64), (20, 200), (30, 150)] {
+        world.current_block().block_timestamp(timestamp);
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .set_tier_pricing(1u8, price)
+            .run();
+    }
+
+    let history = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_tier_price_history(1u8)
+        .returns(ReturnsResult)
+        .run();
+    let history: Vec<_> = history.into_iter().collect();
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[1].tier, 1u8);
+    assert_eq!(history[1].old_price, BigUint::from(100u64));
+    assert_eq!(history[1].new_price, BigUint::from(200u64));
+    assert_eq!(history[1].timestamp, 20u64);
+
+    for (timestamp, expected) in [(5u64, 0u64), (10, 100), (25, 200), (30, 150), (1_000, 150)] {
+        world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .get_tier_price_at(1u8, timestamp)
+            .returns(ExpectValue(BigUint::<StaticApi>::from(expected)))
+            .run();
+    }
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_tier_price_at(2u8, 1_000u64)
+        .returns(ExpectValue(BigUint::<StaticApi>::zero()))
+        .run();
+}
+
+/// Test 42: Treasury settings are timelocked — only tightening withdrawal caps is immediate.
+#[test]
+fn timelocked_treasury_settings() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(NON_OWNER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .grant_role(NON_OWNER_ADDRESS.to_managed_address(), ROLE_ADMIN)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_timelock_delay(DAY)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_payout_address(PAYOUT_ADDRESS.to_managed_address())
+        .with_result(ExpectError(4, "Action is timelocked, use queueAction"))
+        .run();
+
+    let mut split = MultiValueEncoded::<StaticApi, MultiValue2<ManagedAddress<StaticApi>, u32>>::new();
+    split.push((PAYEE_A_ADDRESS.to_managed_address(), 10_000u32).into());
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_revenue_split(split)
+        .with_result(ExpectError(4, "Action is timelocked, use queueAction"))
+        .run();
+
+    // Capping an unlimited treasury only tightens it, so it applies at once
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_withdrawal_limits(4_000_000_000_000_000u64, 6_000_000_000_000_000u64)
+        .run();
+
+    // Raising the daily cap, or lifting both caps, must wait out the delay
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_withdrawal_limits(4_000_000_000_000_000u64, 8_000_000_000_000_000u64)
+        .with_result(ExpectError(4, "Action is timelocked, use queueAction"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .set_withdrawal_limits(0u64, 0u64)
+        .with_result(ExpectError(4, "Action is timelocked, use queueAction"))
+        .run();
+
+    // Treasury actions stay owner-only when queued
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .queue_action(AdminAction::SetPayoutAddress(
+            NON_OWNER_ADDRESS.to_managed_address(),
+        ))
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    let payout_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .queue_action(AdminAction::SetPayoutAddress(
+            PAYOUT_ADDRESS.to_managed_address(),
+        ))
+        .returns(ReturnsResult)
+        .run();
+
+    let mut shares = ManagedVec::<StaticApi, RevenueShare<StaticApi>>::new();
+    shares.push(RevenueShare {
+        payee: PAYEE_A_ADDRESS.to_managed_address(),
+        share_bps: 10_000u32,
+    });
+    let split_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .queue_action(AdminAction::SetRevenueSplit(shares))
+        .returns(ReturnsResult)
+        .run();
+
+    let limits_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .queue_action(AdminAction::SetWithdrawalLimits(
+            BigUint::zero(),
+            BigUint::zero(),
+        ))
+        .returns(ReturnsResult)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .execute_queued(payout_id)
+        .with_result(ExpectError(4, "Timelock delay has not passed"))
+        .run();
+
+    world.current_block().block_timestamp(DAY);
+
+    world
+        .tx()
+        .from(NON_OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .execute_queued(split_id)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    for id in [payout_id, split_id, limits_id] {
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .execute_queued(id)
+            .run();
+    }
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_payout_address()
+        .returns(ExpectValue(PAYOUT_ADDRESS))
+        .run();
+
+    let split = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .get_revenue_split()
+        .returns(ReturnsResult)
+        .run();
+    let split: Vec<_> = split.into_iter().map(|entry| entry.into_tuple()).collect();
+    assert_eq!(split, vec![(PAYEE_A_ADDRESS.to_managed_address(), 10_000u32)]);
+
+    // While timelocked, the owner key cannot send a withdrawal anywhere but the payout address
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .withdraw(1u64, OptionalValue::Some(USER_ADDRESS.to_managed_address()))
+        .with_result(ExpectError(4, "Recipient must be the payout address while timelocked"))
+        .run();
+
+    // Invalid actions fail when queued, not after the delay
+    let mut duplicate = ManagedVec::<StaticApi, RevenueShare<StaticApi>>::new();
+    let mut oversized = ManagedVec::<StaticApi, RevenueShare<StaticApi>>::new();
+    for payee in [PAYEE_A_ADDRESS, PAYEE_A_ADDRESS] {
+        duplicate.push(RevenueShare {
+            payee: payee.to_managed_address(),
+            share_bps: 1_000u32,
+        });
+    }
+    for payee in [PAYEE_A_ADDRESS, PAYEE_B_ADDRESS] {
+        oversized.push(RevenueShare {
+            payee: payee.to_managed_address(),
+            share_bps: 6_000u32,
+        });
+    }
+    let invalid_actions = [
+        (AdminAction::SetTierPricing(4u8, BigUint::from(1u64)), "Invalid tier (1-3)"),
+        (AdminAction::SetTimelockDelay(MAX_TIMELOCK_DELAY + 1), "Timelock delay exceeds maximum"),
+        (AdminAction::SetPayoutAddress(ManagedAddress::zero()), "Payout address cannot be zero"),
+        (AdminAction::SetRevenueSplit(duplicate), "Duplicate payee"),
+        (AdminAction::SetRevenueSplit(oversized), "Shares exceed 10000 basis points"),
+    ];
+    for (action, message) in invalid_actions {
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .queue_action(action)
+            .with_result(ExpectError(4, message))
+            .run();
+    }
+}
+
+/// Test 43: An upload begun by a relayer that is later removed can still be aborted.
+#[test]
+fn abort_upload_of_removed_relayer() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1).balance(100_000_000_000_000_000_000u128);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+    world.account(RELAYER_B_ADDRESS).nonce(1).balance(1_000_000_000_000_000_000u128);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    for (relayer, label) in [(RELAYER_ADDRESS, "eu-west"), (RELAYER_B_ADDRESS, "us-east")] {
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .add_relayer(relayer.to_managed_address(), label, 0u64)
+            .run();
+    }
+
+    create_debate(&mut world, 1u64);
+
+    let text = "Stranded upload";
+    let content_hash: [u8; 32] = Sha256::digest(text.as_bytes()).into();
+    for id in [1u64, 2u64] {
+        world
+            .tx()
+            .from(RELAYER_B_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .begin_argument(
+                id,
+                1u64,
+                1u8,
+                5000u32,
+                text.len() as u32,
+                ManagedByteArray::<StaticApi, 32>::new_from_bytes(&content_hash),
+                no_simhash(),
+                no_author(),
+            )
+            .run();
+    }
+
+    // While its relayer is active, another relayer cannot abort the upload
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .abort_argument(1u64)
+        .with_result(ExpectError(4, "Upload belongs to another relayer"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .remove_relayer(RELAYER_B_ADDRESS.to_managed_address())
+        .run();
+
+    world
+        .tx()
+        .from(RELAYER_B_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .finalize_argument(1u64)
+        .with_result(ExpectError(4, "Only relayer can store arguments"))
+        .run();
+
+    // Once the original relayer is removed, any relayer may free the ID
+    world
+        .tx()
+        .from(RELAYER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .abort_argument(1u64)
+        .run();
+
+    // An admin may always abort
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(dialectical_payments::DialecticalPaymentsProxy)
+        .abort_argument(2u64)
+        .run();
+
+    // Both IDs are free again
+    for id in [1u64, 2u64] {
+        world
+            .tx()
+            .from(RELAYER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(dialectical_payments::DialecticalPaymentsProxy)
+            .store_argument(id, 1u64, 1u8, 5000u32, text, no_simhash(), no_author(), no_signature())
+            .run();
+    }
+}